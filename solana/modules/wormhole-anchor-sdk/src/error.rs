@@ -0,0 +1,38 @@
+use anchor_lang::prelude::error_code;
+
+#[error_code(offset = 9000)]
+/// Errors raised by SDK helpers. These are offset so they do not collide with
+/// the error codes of the program integrating this SDK.
+pub enum SdkError {
+    #[msg("AmountOverflow")]
+    /// Amount does not fit in a u64 after (de)normalization.
+    AmountOverflow,
+
+    #[msg("DecimalsOverflow")]
+    /// Mint decimals are too large to compute the amount adjustment.
+    DecimalsOverflow,
+
+    #[msg("MissingWrappedMeta")]
+    /// Token Bridge wrapped metadata is required to resolve a wrapped mint.
+    MissingWrappedMeta,
+
+    #[msg("InvalidWrappedMeta")]
+    /// Token Bridge wrapped metadata disagrees with the transferred token.
+    InvalidWrappedMeta,
+
+    #[msg("InvalidAuthorityBump")]
+    /// Specified bump does not derive a valid sender or redeemer PDA.
+    InvalidAuthorityBump,
+
+    #[msg("InvalidTokenMetadata")]
+    /// Metaplex metadata account is not the PDA derived from the mint.
+    InvalidTokenMetadata,
+
+    #[msg("InvalidTransferPayload")]
+    /// Token Bridge transfer payload failed to deserialize.
+    InvalidTransferPayload,
+
+    #[msg("UnsupportedMint")]
+    /// Mint is owned by a token program the Token Bridge does not support.
+    UnsupportedMint,
+}
@@ -1,8 +1,12 @@
 #![allow(clippy::result_large_err)]
 
+pub mod error;
 pub mod wormhole;
 
 #[cfg(feature = "token-bridge")]
 pub mod token_bridge;
 
+#[cfg(feature = "nft-bridge")]
+pub mod nft_bridge;
+
 extern crate cfg_if;
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use std::ops::Deref;
+
+use super::{message::Transfer, program::NftBridge};
+use crate::wormhole::PostedVaa;
+
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+/// NFT Bridge config data.
+pub struct Config {
+    pub wormhole_bridge: Pubkey,
+}
+
+impl Config {
+    /// AKA `b"config"`
+    pub const SEED_PREFIX: &'static [u8; 6] = b"config";
+}
+
+impl AccountDeserialize for Config {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::deserialize(buf).map_err(Into::into)
+    }
+}
+
+impl AccountSerialize for Config {}
+
+impl Owner for Config {
+    fn owner() -> Pubkey {
+        NftBridge::id()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+/// NFT Bridge wrapped mint. See [`anchor_spl::token::Mint`].
+pub struct WrappedMint(anchor_spl::token::Mint);
+
+impl WrappedMint {
+    /// AKA `b"wrapped"`
+    pub const SEED_PREFIX: &'static [u8; 7] = b"wrapped";
+
+    /// Derive the NFT Bridge wrapped mint PDA (and its bump) for an NFT
+    /// native to a foreign chain. Each token ID has its own mint.
+    pub fn find_address(
+        token_chain: u16,
+        token_address: &[u8; 32],
+        token_id: &[u8; 32],
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX,
+                &token_chain.to_be_bytes(),
+                token_address,
+                token_id,
+            ],
+            &NftBridge::id(),
+        )
+    }
+}
+
+impl AccountDeserialize for WrappedMint {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Ok(Self(anchor_spl::token::Mint::try_deserialize_unchecked(
+            buf,
+        )?))
+    }
+}
+
+impl AccountSerialize for WrappedMint {}
+
+impl Owner for WrappedMint {
+    fn owner() -> Pubkey {
+        anchor_spl::token::ID
+    }
+}
+
+impl Deref for WrappedMint {
+    type Target = anchor_spl::token::Mint;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+/// NFT Bridge wrapped metadata (for native NFT data).
+pub struct WrappedMeta {
+    pub chain: u16,
+    pub token_address: [u8; 32],
+    /// Token ID as a uint256 with little-endian limbs.
+    pub token_id: [u64; 4],
+}
+
+impl WrappedMeta {
+    /// AKA `b"meta"`
+    pub const SEED_PREFIX: &'static [u8; 4] = b"meta";
+
+    /// Derive the NFT Bridge wrapped metadata PDA (and its bump) for a
+    /// wrapped mint.
+    pub fn find_address(wrapped_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED_PREFIX, wrapped_mint.as_ref()],
+            &NftBridge::id(),
+        )
+    }
+
+    /// Token ID (big-endian uint256), which is how it is encoded in transfers.
+    pub fn token_id(&self) -> [u8; 32] {
+        let mut token_id = [0; 32];
+        for (i, limb) in self.token_id.iter().enumerate() {
+            token_id[(24 - 8 * i)..(32 - 8 * i)].copy_from_slice(&limb.to_be_bytes());
+        }
+        token_id
+    }
+
+    /// Convenience method to check whether the wrapped mint is the one derived
+    /// from this metadata's native chain, token address and token ID.
+    pub fn verify(&self, wrapped_mint: &Pubkey) -> bool {
+        WrappedMint::find_address(self.chain, &self.token_address, &self.token_id()).0
+            == *wrapped_mint
+    }
+}
+
+impl AccountDeserialize for WrappedMeta {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::deserialize(buf).map_err(Into::into)
+    }
+}
+
+impl AccountSerialize for WrappedMeta {}
+
+impl Owner for WrappedMeta {
+    fn owner() -> Pubkey {
+        NftBridge::id()
+    }
+}
+
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+/// NFT Bridge foreign endpoint registration data.
+pub struct EndpointRegistration {
+    pub chain: u16,
+    pub contract: [u8; 32],
+}
+
+impl EndpointRegistration {
+    /// Derive the NFT Bridge foreign endpoint PDA (and its bump), which is
+    /// created when a foreign NFT Bridge is registered via governance.
+    pub fn find_address(chain: u16, contract: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&chain.to_be_bytes(), contract], &NftBridge::id())
+    }
+
+    /// Convenience method to check whether this registration is for the
+    /// specified chain and that its address is the PDA derived from it.
+    pub fn verify(&self, key: &Pubkey, chain: u16) -> bool {
+        self.chain == chain && Self::find_address(self.chain, &self.contract).0 == *key
+    }
+}
+
+impl AccountDeserialize for EndpointRegistration {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Self::deserialize(buf).map_err(Into::into)
+    }
+}
+
+impl AccountSerialize for EndpointRegistration {}
+
+impl Owner for EndpointRegistration {
+    fn owner() -> Pubkey {
+        NftBridge::id()
+    }
+}
+
+/// Posted VAA (verified Wormhole message) of an NFT Bridge transfer.
+pub type PostedTransfer = PostedVaa<Transfer>;
+
+#[cfg(feature = "idl-build")]
+impl Discriminator for Config {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+#[cfg(feature = "idl-build")]
+impl Discriminator for EndpointRegistration {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+#[cfg(feature = "idl-build")]
+impl Discriminator for WrappedMint {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+#[cfg(feature = "idl-build")]
+impl Discriminator for WrappedMeta {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+#[cfg(feature = "idl-build")]
+impl IdlBuild for Config {}
+
+#[cfg(feature = "idl-build")]
+impl IdlBuild for EndpointRegistration {}
+
+#[cfg(feature = "idl-build")]
+impl IdlBuild for WrappedMint {}
+
+#[cfg(feature = "idl-build")]
+impl IdlBuild for WrappedMeta {}
+
+#[test]
+fn wrapped_meta_token_id() {
+    let meta = WrappedMeta {
+        chain: 2,
+        token_address: Pubkey::new_unique().to_bytes(),
+        token_id: [69, 0, 0, 1],
+    };
+
+    let token_id = meta.token_id();
+    assert_eq!(token_id[7], 1);
+    assert_eq!(token_id[31], 69);
+    assert_eq!(token_id.iter().map(|&b| b as u32).sum::<u32>(), 70);
+
+    let (wrapped_mint, _) = WrappedMint::find_address(meta.chain, &meta.token_address, &token_id);
+    assert!(meta.verify(&wrapped_mint));
+    assert!(!meta.verify(&Pubkey::new_unique()));
+}
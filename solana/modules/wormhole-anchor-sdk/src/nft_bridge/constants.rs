@@ -0,0 +1,8 @@
+// seeds
+pub const SEED_PREFIX_AUTHORITY_SIGNER: &[u8; 16] = b"authority_signer";
+pub const SEED_PREFIX_CUSTODY_SIGNER: &[u8; 14] = b"custody_signer";
+pub const SEED_PREFIX_MINT_AUTHORITY: &[u8; 11] = b"mint_signer";
+pub const SEED_PREFIX_EMITTER: &[u8; 7] = b"emitter";
+
+// transfer
+pub const MAX_URI_LENGTH: usize = 200;
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use std::io;
+use wormhole_io::{Readable, Writeable};
+
+use crate::wormhole::CHAIN_ID_SOLANA;
+
+use super::MAX_URI_LENGTH;
+
+pub const PAYLOAD_ID_TRANSFER: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// NFT Bridge Transfer data. This data is found as the payload of a posted
+/// Wormhole message.
+pub struct Transfer {
+    /// Address of the NFT collection. Left-zero-padded if shorter than 32
+    /// bytes
+    pub token_address: [u8; 32],
+    /// Chain ID of the NFT collection
+    pub token_chain: u16,
+    /// Symbol of the NFT collection. Right-zero-padded if shorter than 32
+    /// bytes
+    pub symbol: [u8; 32],
+    /// Name of the NFT collection. Right-zero-padded if shorter than 32 bytes
+    pub name: [u8; 32],
+    /// Token ID (big-endian uint256)
+    pub token_id: [u8; 32],
+    /// URI of the token metadata
+    pub uri: String,
+    /// Address of the recipient. Left-zero-padded if shorter than 32 bytes
+    pub to_address: [u8; 32],
+    /// Chain ID of the recipient
+    pub to_chain: u16,
+}
+
+impl Transfer {
+    pub fn token_address(&self) -> &[u8; 32] {
+        &self.token_address
+    }
+
+    pub fn mint(&self) -> Pubkey {
+        if self.token_chain == CHAIN_ID_SOLANA {
+            Pubkey::new_from_array(self.token_address)
+        } else {
+            Pubkey::default()
+        }
+    }
+
+    pub fn token_chain(&self) -> u16 {
+        self.token_chain
+    }
+
+    /// Symbol without its zero padding.
+    pub fn symbol(&self) -> String {
+        from_padded_bytes(&self.symbol)
+    }
+
+    /// Name without its zero padding.
+    pub fn name(&self) -> String {
+        from_padded_bytes(&self.name)
+    }
+
+    pub fn token_id(&self) -> &[u8; 32] {
+        &self.token_id
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    pub fn to_address(&self) -> &[u8; 32] {
+        &self.to_address
+    }
+
+    pub fn to(&self) -> Pubkey {
+        Pubkey::new_from_array(self.to_address)
+    }
+
+    pub fn to_chain(&self) -> u16 {
+        self.to_chain
+    }
+}
+
+/// Right-zero-pad a name or symbol to 32 bytes. Returns `None` if it is too
+/// long.
+pub fn to_padded_bytes(value: &str) -> Option<[u8; 32]> {
+    let bytes = value.as_bytes();
+    if bytes.len() > 32 {
+        return None;
+    }
+
+    let mut padded = [0; 32];
+    padded[..bytes.len()].copy_from_slice(bytes);
+    Some(padded)
+}
+
+fn from_padded_bytes(value: &[u8; 32]) -> String {
+    let end = value.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    String::from_utf8_lossy(&value[..end]).into_owned()
+}
+
+impl Writeable for Transfer {
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let Transfer {
+            token_address,
+            token_chain,
+            symbol,
+            name,
+            token_id,
+            uri,
+            to_address,
+            to_chain,
+        } = self;
+
+        if uri.len() > MAX_URI_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "NFT Bridge Transfer URI too long",
+            ));
+        }
+
+        PAYLOAD_ID_TRANSFER.write(writer)?;
+        token_address.write(writer)?;
+        token_chain.write(writer)?;
+        symbol.write(writer)?;
+        name.write(writer)?;
+        token_id.write(writer)?;
+        (uri.len() as u8).write(writer)?;
+        writer.write_all(uri.as_bytes())?;
+        to_address.write(writer)?;
+        to_chain.write(writer)?;
+
+        Ok(())
+    }
+
+    fn written_size(&self) -> usize {
+        1 + 32 + 2 + 32 + 32 + 32 + 1 + self.uri.len() + 32 + 2
+    }
+}
+
+impl Readable for Transfer {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        // Verify Payload ID is an NFT transfer.
+        if u8::read(reader)? != PAYLOAD_ID_TRANSFER {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid NFT Bridge Transfer",
+            ));
+        }
+
+        let token_address = Readable::read(reader)?;
+        let token_chain = Readable::read(reader)?;
+        let symbol = Readable::read(reader)?;
+        let name = Readable::read(reader)?;
+        let token_id = Readable::read(reader)?;
+
+        let mut uri = vec![0; u8::read(reader)?.into()];
+        reader.read_exact(&mut uri)?;
+        let uri = String::from_utf8(uri).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "NFT Bridge Transfer URI not UTF-8",
+            )
+        })?;
+
+        let to_address = Readable::read(reader)?;
+        let to_chain = Readable::read(reader)?;
+
+        Ok(Transfer {
+            token_address,
+            token_chain,
+            symbol,
+            name,
+            token_id,
+            uri,
+            to_address,
+            to_chain,
+        })
+    }
+}
+
+impl AnchorSerialize for Transfer {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write(writer)
+    }
+}
+
+impl AnchorDeserialize for Transfer {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Readable::read(reader)
+    }
+}
+
+#[test]
+fn transfer_roundtrip_serialization() {
+    let mut token_id = [0; 32];
+    token_id[31] = 69;
+
+    let original = Transfer {
+        token_address: Pubkey::new_unique().to_bytes(),
+        token_chain: 2,
+        symbol: to_padded_bytes("HELLO").unwrap(),
+        name: to_padded_bytes("Hello NFT").unwrap(),
+        token_id,
+        uri: String::from("https://example.com/69.json"),
+        to_address: Pubkey::new_unique().to_bytes(),
+        to_chain: CHAIN_ID_SOLANA,
+    };
+
+    let encoded = original.try_to_vec().expect("Serialization should work");
+    assert_eq!(encoded.len(), original.written_size());
+    assert_eq!(encoded[0], PAYLOAD_ID_TRANSFER);
+
+    let deserialized =
+        Transfer::deserialize(&mut encoded.as_slice()).expect("Deserialization should work");
+    assert_eq!(original, deserialized);
+    assert_eq!(deserialized.symbol(), "HELLO");
+    assert_eq!(deserialized.name(), "Hello NFT");
+    assert_eq!(deserialized.mint(), Pubkey::default());
+
+    // URI cannot exceed the maximum length.
+    let too_long = Transfer {
+        uri: "a".repeat(MAX_URI_LENGTH + 1),
+        ..original
+    };
+    assert!(too_long.try_to_vec().is_err());
+    assert!(to_padded_bytes(&"a".repeat(33)).is_none());
+}
@@ -0,0 +1,10 @@
+pub use accounts::*;
+pub use constants::*;
+pub use instructions::*;
+pub use message::*;
+
+pub mod accounts;
+pub mod constants;
+pub mod instructions;
+pub mod message;
+pub mod program;
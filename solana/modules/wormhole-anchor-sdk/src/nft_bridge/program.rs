@@ -0,0 +1,20 @@
+use anchor_lang::{prelude::*, solana_program};
+
+#[derive(Debug, Clone)]
+pub struct NftBridge;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "tilt-devnet")] {
+        pub static ID: Pubkey = solana_program::pubkey!("NFTWqJR8YnRVqPDvTJrYuLrQDitTG5AScqbeghi4zSA");
+    } else if #[cfg(feature = "solana-devnet")] {
+        pub static ID: Pubkey = solana_program::pubkey!("2rHhojZ7hpu1zA91nvZmT8TqWWvMcKmmNBCr2mKTtMq4");
+    } else {
+        pub static ID: Pubkey = solana_program::pubkey!("WnFt12ZrnzZrFZkt2xsNsaNWoQribnuQ5B5FrDbwDhD");
+    }
+}
+
+impl Id for NftBridge {
+    fn id() -> Pubkey {
+        ID
+    }
+}
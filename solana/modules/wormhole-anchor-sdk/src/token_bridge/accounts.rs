@@ -3,12 +3,13 @@ use std::io::Write;
 use std::{io, ops::Deref};
 use wormhole_io::{Readable, Writeable, WriteableBytes};
 
-use crate::token_bridge::message::TransferHeader;
+use crate::error::SdkError;
+use crate::token_bridge::{message::TransferHeader, utils::NormalizedAmount};
 
 use super::program::TokenBridge;
 use crate::wormhole::{PostedVaa, CHAIN_ID_SOLANA};
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 /// Token Bridge config data.
 pub struct Config {
     pub wormhole_bridge: Pubkey,
@@ -40,6 +41,15 @@ pub struct WrappedMint(anchor_spl::token::Mint);
 impl WrappedMint {
     /// AKA `b"wrapped"`
     pub const SEED_PREFIX: &'static [u8; 7] = b"wrapped";
+
+    /// Derive the Token Bridge wrapped mint PDA (and its bump) for a token
+    /// native to a foreign chain.
+    pub fn find_address(token_chain: u16, token_address: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED_PREFIX, &token_chain.to_be_bytes(), token_address],
+            &TokenBridge::id(),
+        )
+    }
 }
 
 impl AccountDeserialize for WrappedMint {
@@ -66,7 +76,7 @@ impl Deref for WrappedMint {
     }
 }
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 /// Token Bridge wrapped metadata (for native token data).
 pub struct WrappedMeta {
     pub chain: u16,
@@ -77,6 +87,79 @@ pub struct WrappedMeta {
 impl WrappedMeta {
     /// AKA `b"meta"`
     pub const SEED_PREFIX: &'static [u8; 4] = b"meta";
+
+    /// Derive the Token Bridge wrapped metadata PDA (and its bump) for a
+    /// wrapped mint.
+    pub fn find_address(wrapped_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED_PREFIX, wrapped_mint.as_ref()],
+            &TokenBridge::id(),
+        )
+    }
+
+    /// Convenience method to check whether the wrapped mint is the one derived
+    /// from this metadata's native chain and token address.
+    pub fn verify(&self, wrapped_mint: &Pubkey) -> bool {
+        WrappedMint::find_address(self.chain, &self.token_address).0 == *wrapped_mint
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Mint of the SPL token that a Token Bridge transfer is redeemed with.
+pub enum ResolvedMint {
+    /// Token native to Solana. The token address is the mint.
+    Native(Pubkey),
+    /// Token native to a foreign chain, which is minted by the Token Bridge.
+    Wrapped { mint: Pubkey, meta: WrappedMeta },
+}
+
+impl ResolvedMint {
+    pub fn mint(&self) -> Pubkey {
+        match self {
+            ResolvedMint::Native(mint) => *mint,
+            ResolvedMint::Wrapped { mint, .. } => *mint,
+        }
+    }
+
+    pub fn is_native(&self) -> bool {
+        matches!(self, ResolvedMint::Native(_))
+    }
+
+    /// Token's native decimals. Native mints are not truncated, so this is only
+    /// known for wrapped mints.
+    pub fn original_decimals(&self) -> Option<u8> {
+        match self {
+            ResolvedMint::Native(_) => None,
+            ResolvedMint::Wrapped { meta, .. } => Some(meta.original_decimals),
+        }
+    }
+}
+
+/// Resolve the mint for a token identified by its native chain and address.
+/// Wrapped tokens require the Token Bridge wrapped metadata account, which must
+/// be the PDA derived from the wrapped mint and agree with the token info.
+pub fn resolve_mint(
+    token_chain: u16,
+    token_address: &[u8; 32],
+    wrapped_meta: Option<&Account<WrappedMeta>>,
+) -> Result<ResolvedMint> {
+    if token_chain == CHAIN_ID_SOLANA {
+        return Ok(ResolvedMint::Native(Pubkey::new_from_array(*token_address)));
+    }
+
+    let wrapped_meta = wrapped_meta.ok_or(error!(SdkError::MissingWrappedMeta))?;
+    let (mint, _) = WrappedMint::find_address(token_chain, token_address);
+    require!(
+        wrapped_meta.key() == WrappedMeta::find_address(&mint).0
+            && wrapped_meta.chain == token_chain
+            && wrapped_meta.token_address == *token_address,
+        SdkError::InvalidWrappedMeta
+    );
+
+    Ok(ResolvedMint::Wrapped {
+        mint,
+        meta: WrappedMeta::clone(wrapped_meta),
+    })
 }
 
 impl AccountDeserialize for WrappedMeta {
@@ -93,13 +176,31 @@ impl Owner for WrappedMeta {
     }
 }
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 /// Token Bridge foreign endpoint registration data.
 pub struct EndpointRegistration {
     pub emitter_chain: u16,
     pub emitter_address: [u8; 32],
 }
 
+impl EndpointRegistration {
+    /// Derive the Token Bridge foreign endpoint PDA (and its bump), which is
+    /// created when a foreign Token Bridge is registered via governance.
+    pub fn find_address(emitter_chain: u16, emitter_address: &[u8; 32]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[&emitter_chain.to_be_bytes(), emitter_address],
+            &TokenBridge::id(),
+        )
+    }
+
+    /// Convenience method to check whether this registration is for the
+    /// specified chain and that its address is the PDA derived from it.
+    pub fn verify(&self, key: &Pubkey, emitter_chain: u16) -> bool {
+        self.emitter_chain == emitter_chain
+            && Self::find_address(self.emitter_chain, &self.emitter_address).0 == *key
+    }
+}
+
 impl AccountDeserialize for EndpointRegistration {
     fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
         Self::deserialize(buf).map_err(Into::into)
@@ -127,6 +228,11 @@ impl TransferWithPayload {
         self.meta.amount
     }
 
+    /// Encoded amount, which is normalized to at most 8 decimals.
+    pub fn normalized_amount(&self) -> NormalizedAmount {
+        NormalizedAmount::new(self.meta.amount)
+    }
+
     pub fn token_address(&self) -> &[u8; 32] {
         &self.meta.token_address
     }
@@ -139,6 +245,15 @@ impl TransferWithPayload {
         }
     }
 
+    /// Resolve the native mint or the Token Bridge wrapped mint (with its
+    /// metadata) for the transferred token. See [`resolve_mint`].
+    pub fn resolve_mint(
+        &self,
+        wrapped_meta: Option<&Account<WrappedMeta>>,
+    ) -> Result<ResolvedMint> {
+        resolve_mint(self.token_chain(), self.token_address(), wrapped_meta)
+    }
+
     pub fn token_chain(&self) -> u16 {
         self.meta.token_chain
     }
@@ -265,11 +380,18 @@ impl<P: AnchorDeserialize + AnchorSerialize + Copy> TransferWith<P> {
             payload: *payload,
         }
     }
+}
 
+impl<P: AnchorDeserialize + AnchorSerialize> TransferWith<P> {
     pub fn amount(&self) -> u64 {
         self.meta.amount
     }
 
+    /// Encoded amount, which is normalized to at most 8 decimals.
+    pub fn normalized_amount(&self) -> NormalizedAmount {
+        NormalizedAmount::new(self.meta.amount)
+    }
+
     pub fn token_address(&self) -> &[u8; 32] {
         &self.meta.token_address
     }
@@ -282,6 +404,15 @@ impl<P: AnchorDeserialize + AnchorSerialize + Copy> TransferWith<P> {
         }
     }
 
+    /// Resolve the native mint or the Token Bridge wrapped mint (with its
+    /// metadata) for the transferred token. See [`resolve_mint`].
+    pub fn resolve_mint(
+        &self,
+        wrapped_meta: Option<&Account<WrappedMeta>>,
+    ) -> Result<ResolvedMint> {
+        resolve_mint(self.token_chain(), self.token_address(), wrapped_meta)
+    }
+
     pub fn token_chain(&self) -> u16 {
         self.meta.token_chain
     }
@@ -331,6 +462,91 @@ impl<P: AnchorSerialize + AnchorDeserialize> AnchorSerialize for TransferWith<P>
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error describing why the payload of a Token Bridge transfer failed to
+/// deserialize.
+pub struct PayloadError {
+    message: String,
+}
+
+impl PayloadError {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for PayloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid transfer payload: {}", self.message)
+    }
+}
+
+impl From<PayloadError> for anchor_lang::error::Error {
+    fn from(_: PayloadError) -> Self {
+        error!(SdkError::InvalidTransferPayload)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Application payload of type `P` that may fail to deserialize. The raw
+/// payload is always kept, so a malformed payload does not prevent loading the
+/// transfer header. Programs can then implement fallback behavior (e.g.
+/// redeeming to a recovery account) for transfers they cannot parse.
+pub struct FalliblePayload<P> {
+    raw: Vec<u8>,
+    parsed: std::result::Result<P, PayloadError>,
+}
+
+impl<P: AnchorDeserialize> FalliblePayload<P> {
+    /// Attempt to deserialize the raw payload as `P`.
+    pub fn new(raw: Vec<u8>) -> Self {
+        let parsed = P::deserialize(&mut raw.as_slice()).map_err(|err| PayloadError {
+            message: err.to_string(),
+        });
+        Self { raw, parsed }
+    }
+}
+
+impl<P> FalliblePayload<P> {
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    pub fn parsed(&self) -> std::result::Result<&P, &PayloadError> {
+        self.parsed.as_ref()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.parsed.is_ok()
+    }
+
+    /// Deserialized payload, which is useful with `?` when there is no
+    /// fallback behavior.
+    pub fn try_get(&self) -> Result<&P> {
+        self.parsed().map_err(|err| err.clone().into())
+    }
+
+    pub fn into_parsed(self) -> std::result::Result<P, PayloadError> {
+        self.parsed
+    }
+}
+
+/// The payload is the rest of the reader. [`PostedVaa`] bounds the reader to
+/// the VAA's payload length.
+impl<P: AnchorDeserialize> AnchorDeserialize for FalliblePayload<P> {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut raw = Vec::new();
+        reader.read_to_end(&mut raw)?;
+        Ok(Self::new(raw))
+    }
+}
+
+impl<P> AnchorSerialize for FalliblePayload<P> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.raw)
+    }
+}
+
 /// Posted VAA (verified Wormhole message) of a Token Bridge transfer with
 /// payload.
 pub type PostedTransferWithPayload = PostedVaa<TransferWithPayload>;
@@ -339,6 +555,11 @@ pub type PostedTransferWithPayload = PostedVaa<TransferWithPayload>;
 /// generic payload type `P`.
 pub type PostedTransferWith<P> = PostedVaa<TransferWith<P>>;
 
+/// Posted VAA (verified Wormhole message) of a Token Bridge transfer with
+/// generic payload type `P`, which loads even if the payload is malformed. See
+/// [`FalliblePayload`].
+pub type PostedFallibleTransferWith<P> = PostedVaa<TransferWith<FalliblePayload<P>>>;
+
 #[cfg(feature = "idl-build")]
 impl Discriminator for Config {
     const DISCRIMINATOR: &'static [u8] = &[];
@@ -413,3 +634,80 @@ fn transfer_with_roundtrip_serialization() {
 
     assert_eq!(original, deserialized);
 }
+
+#[test]
+fn wrapped_meta_verify_and_resolve_mint() {
+    let meta = WrappedMeta {
+        chain: 2,
+        token_address: Pubkey::new_unique().to_bytes(),
+        original_decimals: 18,
+    };
+
+    let (wrapped_mint, _) = WrappedMint::find_address(meta.chain, &meta.token_address);
+    assert!(meta.verify(&wrapped_mint));
+    assert!(!meta.verify(&Pubkey::new_unique()));
+
+    // Native tokens do not need wrapped metadata.
+    let native_mint = Pubkey::new_unique();
+    let resolved = resolve_mint(CHAIN_ID_SOLANA, &native_mint.to_bytes(), None)
+        .expect("Native mint should resolve");
+    assert_eq!(resolved, ResolvedMint::Native(native_mint));
+    assert_eq!(resolved.original_decimals(), None);
+
+    // But wrapped tokens do.
+    assert!(resolve_mint(meta.chain, &meta.token_address, None).is_err());
+}
+
+#[test]
+fn fallible_transfer_with_deserialization() {
+    let rng = &mut rand::thread_rng();
+    let meta = TransferHeader::random(rng);
+
+    // Well-formed payload.
+    let payload = Pubkey::new_unique();
+    let encoded = TransferWith::new(&meta, &payload)
+        .try_to_vec()
+        .expect("Serialization should work");
+    let transfer = TransferWith::<FalliblePayload<Pubkey>>::deserialize(&mut encoded.as_slice())
+        .expect("Deserialization should work");
+    assert_eq!(transfer.data().parsed(), Ok(&payload));
+    assert_eq!(transfer.data().raw(), payload.as_ref());
+    assert_eq!(
+        transfer.try_to_vec().expect("Serialization should work"),
+        encoded
+    );
+
+    // Malformed payload still loads the header.
+    let mut encoded = meta.try_to_vec().expect("Serialization should work");
+    encoded.extend_from_slice(&[1, 2, 3]);
+    let transfer = TransferWith::<FalliblePayload<Pubkey>>::deserialize(&mut encoded.as_slice())
+        .expect("Deserialization should work");
+    assert_eq!(transfer.amount(), meta.amount);
+    assert_eq!(transfer.from_address(), &meta.from_address);
+    assert!(!transfer.data().is_ok());
+    assert_eq!(transfer.data().raw(), &[1, 2, 3]);
+    assert!(transfer.data().try_get().is_err());
+}
+
+#[test]
+fn posted_fallible_transfer_ignores_trailing_bytes() {
+    let rng = &mut rand::thread_rng();
+    let payload = Pubkey::new_unique();
+    let transfer = TransferWith::new(&TransferHeader::random(rng), &payload)
+        .try_to_vec()
+        .expect("Serialization should work");
+
+    // Posted VAA accounts can be larger than the VAA they hold.
+    let mut encoded = crate::wormhole::PostedVaaMeta::default()
+        .try_to_vec()
+        .expect("Serialization should work");
+    encoded.extend_from_slice(&(transfer.len() as u32).to_le_bytes());
+    encoded.extend_from_slice(&transfer);
+    encoded.extend_from_slice(&[0xff; 7]);
+
+    let posted = PostedFallibleTransferWith::<Pubkey>::deserialize(&mut encoded.as_slice())
+        .expect("Deserialization should work");
+    assert_eq!(posted.payload_size() as usize, transfer.len());
+    assert_eq!(posted.data().data().raw(), payload.as_ref());
+    assert_eq!(posted.data().data().parsed(), Ok(&payload));
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use std::marker::PhantomData;
+
+use crate::error::SdkError;
+
+use super::{SEED_PREFIX_REDEEMER, SEED_PREFIX_SENDER};
+
+/// Seed prefix of an [`Authority`] PDA.
+pub trait AuthoritySeed {
+    const SEED_PREFIX: &'static [u8];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Seed of the sender PDA. AKA `b"sender"`.
+pub struct Sender;
+
+impl AuthoritySeed for Sender {
+    const SEED_PREFIX: &'static [u8] = SEED_PREFIX_SENDER;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Seed of the redeemer PDA. AKA `b"redeemer"`.
+pub struct Redeemer;
+
+impl AuthoritySeed for Redeemer {
+    const SEED_PREFIX: &'static [u8] = SEED_PREFIX_REDEEMER;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// PDA of a program integrating Token Bridge transfers with payload, derived
+/// from the seed prefix of `S` only.
+pub struct Authority<S: AuthoritySeed> {
+    bump: u8,
+    seed: PhantomData<S>,
+}
+
+/// Sender PDA of a program integrating Token Bridge transfers with payload.
+/// When `cpi_program_id` is specified for an outbound transfer, the Token
+/// Bridge program requires that this PDA (derived from the CPI program ID)
+/// signs for the transfer.
+pub type SenderAuthority = Authority<Sender>;
+
+/// Redeemer PDA of a program integrating Token Bridge transfers with payload.
+/// When the transfer recipient is the integrating program, the Token Bridge
+/// program requires that this PDA (derived from the recipient) signs for
+/// completing the transfer.
+pub type RedeemerAuthority = Authority<Redeemer>;
+
+impl<S: AuthoritySeed> Authority<S> {
+    /// AKA `b"sender"` or `b"redeemer"`.
+    pub const SEED_PREFIX: &'static [u8] = S::SEED_PREFIX;
+
+    pub fn new(bump: u8) -> Self {
+        Self {
+            bump,
+            seed: PhantomData,
+        }
+    }
+
+    /// Derive the PDA (and its bump) for the integrating program.
+    pub fn find_address(program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED_PREFIX], program_id)
+    }
+
+    /// Derive the PDA and hold its bump.
+    pub fn find(program_id: &Pubkey) -> Self {
+        Self::new(Self::find_address(program_id).1)
+    }
+
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// PDA for the integrating program using the stored bump.
+    pub fn address(&self, program_id: &Pubkey) -> Result<Pubkey> {
+        Pubkey::create_program_address(&self.signer_seeds(), program_id)
+            .map_err(|_| error!(SdkError::InvalidAuthorityBump))
+    }
+
+    /// Seeds to sign for the Token Bridge CPI, which can be passed to
+    /// [`CpiContext::new_with_signer`] as `&[&authority.signer_seeds()]`.
+    pub fn signer_seeds(&self) -> [&[u8]; 2] {
+        [S::SEED_PREFIX, std::slice::from_ref(&self.bump)]
+    }
+}
+
+#[test]
+fn authority_derivation() {
+    let program_id = Pubkey::new_unique();
+
+    let (sender, bump) = SenderAuthority::find_address(&program_id);
+    let sender_authority = SenderAuthority::find(&program_id);
+    assert_eq!(sender_authority.bump(), bump);
+    assert_eq!(sender_authority.address(&program_id).unwrap(), sender);
+    assert_eq!(sender_authority.signer_seeds(), [&b"sender"[..], &[bump]]);
+
+    let (redeemer, bump) = RedeemerAuthority::find_address(&program_id);
+    let redeemer_authority = RedeemerAuthority::find(&program_id);
+    assert_eq!(redeemer_authority.bump(), bump);
+    assert_eq!(redeemer_authority.address(&program_id).unwrap(), redeemer);
+    assert_eq!(
+        redeemer_authority.signer_seeds(),
+        [&b"redeemer"[..], &[bump]]
+    );
+    assert_ne!(sender, redeemer);
+}
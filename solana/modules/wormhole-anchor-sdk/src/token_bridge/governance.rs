@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use std::io;
+use wormhole_io::{Readable, Writeable};
+
+use crate::wormhole::PostedVaa;
+
+/// Token Bridge governance module, which is "TokenBridge" left-padded with
+/// zeros to 32 bytes.
+pub const GOVERNANCE_MODULE: [u8; 32] = {
+    let mut module = [0; 32];
+    let name = b"TokenBridge";
+    let mut i = 0;
+    while i < name.len() {
+        module[32 - name.len() + i] = name[i];
+        i += 1;
+    }
+    module
+};
+
+pub const GOVERNANCE_ACTION_REGISTER_CHAIN: u8 = 1;
+pub const GOVERNANCE_ACTION_UPGRADE_CONTRACT: u8 = 2;
+pub const GOVERNANCE_ACTION_RECOVER_CHAIN_ID: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Token Bridge governance actions. This data is found as the payload of a
+/// posted Wormhole message emitted by the governance emitter.
+pub enum TokenBridgeGovernance {
+    /// Register a foreign Token Bridge emitter.
+    RegisterChain {
+        /// Chain ID this action is meant for. Zero means all chains.
+        target_chain: u16,
+        /// Chain ID of the foreign Token Bridge.
+        foreign_chain: u16,
+        /// Emitter address of the foreign Token Bridge.
+        foreign_emitter: [u8; 32],
+    },
+    /// Upgrade the Token Bridge program.
+    UpgradeContract {
+        /// Chain ID this action is meant for.
+        target_chain: u16,
+        /// New implementation. On Solana, this is the program buffer address.
+        new_contract: [u8; 32],
+    },
+    /// Recover the Wormhole Chain ID after an EVM chain fork.
+    RecoverChainId {
+        /// EVM Chain ID (big-endian uint256).
+        evm_chain_id: [u8; 32],
+        /// New Wormhole Chain ID.
+        new_chain_id: u16,
+    },
+}
+
+impl TokenBridgeGovernance {
+    /// Chain ID this action is meant for. Zero means all chains. Chain ID
+    /// recovery is only relevant for EVM networks, so there is no target.
+    pub fn target_chain(&self) -> Option<u16> {
+        match self {
+            TokenBridgeGovernance::RegisterChain { target_chain, .. } => Some(*target_chain),
+            TokenBridgeGovernance::UpgradeContract { target_chain, .. } => Some(*target_chain),
+            TokenBridgeGovernance::RecoverChainId { .. } => None,
+        }
+    }
+}
+
+impl Writeable for TokenBridgeGovernance {
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        GOVERNANCE_MODULE.write(writer)?;
+
+        match self {
+            TokenBridgeGovernance::RegisterChain {
+                target_chain,
+                foreign_chain,
+                foreign_emitter,
+            } => {
+                GOVERNANCE_ACTION_REGISTER_CHAIN.write(writer)?;
+                target_chain.write(writer)?;
+                foreign_chain.write(writer)?;
+                foreign_emitter.write(writer)
+            }
+            TokenBridgeGovernance::UpgradeContract {
+                target_chain,
+                new_contract,
+            } => {
+                GOVERNANCE_ACTION_UPGRADE_CONTRACT.write(writer)?;
+                target_chain.write(writer)?;
+                new_contract.write(writer)
+            }
+            TokenBridgeGovernance::RecoverChainId {
+                evm_chain_id,
+                new_chain_id,
+            } => {
+                GOVERNANCE_ACTION_RECOVER_CHAIN_ID.write(writer)?;
+                evm_chain_id.write(writer)?;
+                new_chain_id.write(writer)
+            }
+        }
+    }
+
+    fn written_size(&self) -> usize {
+        32 + 1
+            + match self {
+                TokenBridgeGovernance::RegisterChain { .. } => 2 + 2 + 32,
+                TokenBridgeGovernance::UpgradeContract { .. } => 2 + 32,
+                TokenBridgeGovernance::RecoverChainId { .. } => 32 + 2,
+            }
+    }
+}
+
+impl Readable for TokenBridgeGovernance {
+    const SIZE: Option<usize> = None;
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        // Verify this action is meant for the Token Bridge.
+        if <[u8; 32]>::read(reader)? != GOVERNANCE_MODULE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid Token Bridge governance module",
+            ));
+        }
+
+        match u8::read(reader)? {
+            GOVERNANCE_ACTION_REGISTER_CHAIN => Ok(TokenBridgeGovernance::RegisterChain {
+                target_chain: Readable::read(reader)?,
+                foreign_chain: Readable::read(reader)?,
+                foreign_emitter: Readable::read(reader)?,
+            }),
+            GOVERNANCE_ACTION_UPGRADE_CONTRACT => Ok(TokenBridgeGovernance::UpgradeContract {
+                target_chain: Readable::read(reader)?,
+                new_contract: Readable::read(reader)?,
+            }),
+            GOVERNANCE_ACTION_RECOVER_CHAIN_ID => Ok(TokenBridgeGovernance::RecoverChainId {
+                evm_chain_id: Readable::read(reader)?,
+                new_chain_id: Readable::read(reader)?,
+            }),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Invalid Token Bridge governance action",
+            )),
+        }
+    }
+}
+
+impl AnchorSerialize for TokenBridgeGovernance {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write(writer)
+    }
+}
+
+impl AnchorDeserialize for TokenBridgeGovernance {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Readable::read(reader)
+    }
+}
+
+/// Posted VAA (verified Wormhole message) of a Token Bridge governance action.
+pub type PostedTokenBridgeGovernance = PostedVaa<TokenBridgeGovernance>;
+
+#[test]
+fn register_chain_deserialization() {
+    // Module + action + target chain (all) + foreign chain (Ethereum) +
+    // foreign emitter.
+    let foreign_emitter = [0x3e; 32];
+    let mut encoded = Vec::from(GOVERNANCE_MODULE);
+    encoded.push(GOVERNANCE_ACTION_REGISTER_CHAIN);
+    encoded.extend_from_slice(&0u16.to_be_bytes());
+    encoded.extend_from_slice(&2u16.to_be_bytes());
+    encoded.extend_from_slice(&foreign_emitter);
+
+    assert_eq!(&GOVERNANCE_MODULE[21..], b"TokenBridge");
+
+    let decoded = TokenBridgeGovernance::deserialize(&mut encoded.as_slice())
+        .expect("Deserialization should work");
+    assert_eq!(
+        decoded,
+        TokenBridgeGovernance::RegisterChain {
+            target_chain: 0,
+            foreign_chain: 2,
+            foreign_emitter,
+        }
+    );
+    assert_eq!(decoded.target_chain(), Some(0));
+}
+
+#[test]
+fn governance_roundtrip_serialization() {
+    for original in [
+        TokenBridgeGovernance::RegisterChain {
+            target_chain: 1,
+            foreign_chain: 2,
+            foreign_emitter: Pubkey::new_unique().to_bytes(),
+        },
+        TokenBridgeGovernance::UpgradeContract {
+            target_chain: 1,
+            new_contract: Pubkey::new_unique().to_bytes(),
+        },
+        TokenBridgeGovernance::RecoverChainId {
+            evm_chain_id: Pubkey::new_unique().to_bytes(),
+            new_chain_id: 69,
+        },
+    ] {
+        let encoded = original.try_to_vec().expect("Serialization should work");
+        assert_eq!(encoded.len(), original.written_size());
+
+        let deserialized = TokenBridgeGovernance::deserialize(&mut encoded.as_slice())
+            .expect("Deserialization should work");
+        assert_eq!(original, deserialized);
+    }
+}
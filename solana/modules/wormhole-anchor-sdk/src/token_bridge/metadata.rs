@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use std::fmt;
+
+use crate::error::SdkError;
+
+use super::{WrappedMeta, WrappedMint};
+
+/// AKA `b"metadata"`.
+pub const SEED_PREFIX_METADATA: &[u8; 8] = b"metadata";
+
+impl WrappedMint {
+    /// Derive the Metaplex metadata PDA (and its bump) of a wrapped mint. The
+    /// Token Bridge creates this account when the token is attested.
+    pub fn find_metadata_address(wrapped_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                SEED_PREFIX_METADATA,
+                anchor_spl::metadata::ID.as_ref(),
+                wrapped_mint.as_ref(),
+            ],
+            &anchor_spl::metadata::ID,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Human-readable name of a Token Bridge wrapped token.
+pub enum WrappedTokenName {
+    /// Name and symbol found in the wrapped mint's Metaplex metadata.
+    Metadata { name: String, symbol: String },
+    /// Origin of the token, used when the Metaplex metadata is missing or
+    /// empty.
+    Origin { chain: u16, token_address: [u8; 32] },
+}
+
+impl WrappedTokenName {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            WrappedTokenName::Metadata { name, .. } => Some(name),
+            WrappedTokenName::Origin { .. } => None,
+        }
+    }
+
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            WrappedTokenName::Metadata { symbol, .. } => Some(symbol),
+            WrappedTokenName::Origin { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for WrappedTokenName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WrappedTokenName::Metadata { name, symbol } => write!(f, "{name} ({symbol})"),
+            WrappedTokenName::Origin {
+                chain,
+                token_address,
+            } => {
+                write!(f, "{chain}:0x")?;
+                token_address
+                    .iter()
+                    .try_for_each(|byte| write!(f, "{byte:02x}"))
+            }
+        }
+    }
+}
+
+/// Resolve the human-readable name of a wrapped token. The wrapped metadata
+/// must be the one for the wrapped mint. If the Metaplex metadata account is
+/// specified, it must be the PDA derived from the wrapped mint.
+///
+/// If the Metaplex metadata is not specified, does not exist or has an empty
+/// name, this falls back to the token's origin chain and address.
+pub fn resolve_wrapped_token_name(
+    wrapped_mint: &Pubkey,
+    wrapped_meta: &WrappedMeta,
+    metadata: Option<&AccountInfo>,
+) -> Result<WrappedTokenName> {
+    require!(
+        wrapped_meta.verify(wrapped_mint),
+        SdkError::InvalidWrappedMeta
+    );
+
+    let origin = WrappedTokenName::Origin {
+        chain: wrapped_meta.chain,
+        token_address: wrapped_meta.token_address,
+    };
+
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        None => return Ok(origin),
+    };
+    require_keys_eq!(
+        metadata.key(),
+        WrappedMint::find_metadata_address(wrapped_mint).0,
+        SdkError::InvalidTokenMetadata
+    );
+
+    // The metadata account does not exist (yet).
+    if *metadata.owner != anchor_spl::metadata::ID || metadata.data_is_empty() {
+        return Ok(origin);
+    }
+
+    let metadata = MetadataAccount::try_deserialize(&mut &metadata.data.borrow()[..])?;
+    match parse_metadata(&metadata.name, &metadata.symbol) {
+        Some(name) => Ok(name),
+        None => Ok(origin),
+    }
+}
+
+/// Metaplex pads names and symbols with null bytes.
+fn parse_metadata(name: &str, symbol: &str) -> Option<WrappedTokenName> {
+    let name = name.trim_end_matches('\0').trim();
+    let symbol = symbol.trim_end_matches('\0').trim();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(WrappedTokenName::Metadata {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+        })
+    }
+}
+
+#[test]
+fn wrapped_token_name() {
+    let parsed = parse_metadata("Wrapped Ether (Wormhole)\0\0\0", "WETH\0\0\0\0\0\0").unwrap();
+    assert_eq!(parsed.name(), Some("Wrapped Ether (Wormhole)"));
+    assert_eq!(parsed.symbol(), Some("WETH"));
+    assert_eq!(parsed.to_string(), "Wrapped Ether (Wormhole) (WETH)");
+    assert!(parse_metadata("\0\0\0\0", "\0\0").is_none());
+
+    let mut token_address = [0; 32];
+    token_address[31] = 0xab;
+    let origin = WrappedTokenName::Origin {
+        chain: 2,
+        token_address,
+    };
+    assert_eq!(origin.name(), None);
+    assert_eq!(
+        origin.to_string(),
+        "2:0x00000000000000000000000000000000000000000000000000000000000000ab"
+    );
+
+    // No metadata account falls back to the origin.
+    let meta = WrappedMeta {
+        chain: 2,
+        token_address,
+        original_decimals: 18,
+    };
+    let (wrapped_mint, _) = WrappedMint::find_address(2, &token_address);
+    assert_eq!(
+        resolve_wrapped_token_name(&wrapped_mint, &meta, None).unwrap(),
+        origin
+    );
+    assert!(resolve_wrapped_token_name(&Pubkey::new_unique(), &meta, None).is_err());
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+};
+
+use crate::error::SdkError;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Mint classified by its owning program (and extensions, for Token-2022).
+pub enum MintKind {
+    /// Mint owned by the SPL Token program.
+    SplToken,
+    /// Mint owned by the Token-2022 program with its extensions (if any).
+    Token2022 { extensions: Vec<ExtensionType> },
+}
+
+impl MintKind {
+    /// Classify a mint account. Errors if the account is not owned by either
+    /// token program or is not a mint.
+    pub fn classify(mint: &AccountInfo) -> Result<Self> {
+        let data = mint.try_borrow_data()?;
+        if *mint.owner == anchor_spl::token::ID {
+            anchor_spl::token::Mint::try_deserialize(&mut &data[..])?;
+            Ok(MintKind::SplToken)
+        } else if *mint.owner == anchor_spl::token_2022::ID {
+            let extensions = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)
+                .and_then(|mint| mint.get_extension_types())
+                .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+            Ok(MintKind::Token2022 { extensions })
+        } else {
+            err!(ErrorCode::AccountOwnedByWrongProgram)
+        }
+    }
+
+    /// Whether the Token Bridge can transfer this mint. Only SPL Token mints
+    /// are supported today.
+    pub fn is_supported(&self) -> bool {
+        match self {
+            MintKind::SplToken => true,
+            // NOTE: When the Token Bridge supports Token-2022, check the
+            // extensions here (e.g. reject transfer fees and hooks).
+            MintKind::Token2022 { .. } => false,
+        }
+    }
+}
+
+/// Classify a mint and error with [`SdkError::UnsupportedMint`] if the Token
+/// Bridge cannot transfer it.
+pub fn require_supported_mint(mint: &AccountInfo) -> Result<MintKind> {
+    let kind = MintKind::classify(mint)?;
+    require!(kind.is_supported(), SdkError::UnsupportedMint);
+    Ok(kind)
+}
+
+#[test]
+fn supported_mints() {
+    assert!(MintKind::SplToken.is_supported());
+    assert!(!MintKind::Token2022 { extensions: vec![] }.is_supported());
+    assert!(!MintKind::Token2022 {
+        extensions: vec![ExtensionType::TransferFeeConfig]
+    }
+    .is_supported());
+
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token;
+
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        decimals: 8,
+        is_initialized: true,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut owner = anchor_spl::token::ID;
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(MintKind::classify(&mint).unwrap(), MintKind::SplToken);
+    assert!(require_supported_mint(&mint).is_ok());
+
+    // A Token-2022 mint without extensions has the same layout.
+    owner = anchor_spl::token_2022::ID;
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_eq!(
+        MintKind::classify(&mint).unwrap(),
+        MintKind::Token2022 { extensions: vec![] }
+    );
+    assert_eq!(
+        require_supported_mint(&mint).unwrap_err(),
+        SdkError::UnsupportedMint.into()
+    );
+
+    owner = Pubkey::new_unique();
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert!(MintKind::classify(&mint).is_err());
+}
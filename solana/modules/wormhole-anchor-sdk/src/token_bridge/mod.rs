@@ -1,12 +1,20 @@
 pub use accounts::*;
+pub use authority::*;
 pub use constants::*;
+pub use governance::*;
 pub use instructions::*;
 pub use message::*;
+pub use metadata::*;
+pub use mint::*;
 pub use utils::*;
 
 pub mod accounts;
+pub mod authority;
 pub mod constants;
+pub mod governance;
 pub mod instructions;
 pub mod message;
+pub mod metadata;
+pub mod mint;
 pub mod program;
 pub mod utils;
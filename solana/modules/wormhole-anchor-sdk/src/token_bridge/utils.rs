@@ -1,6 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::error::SdkError;
+
 pub const MAX_WRAPPED_ASSET_DECIMALS: u8 = 8;
 
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+)]
+/// Amount encoded with at most 8 decimals, which is how the Token Bridge
+/// program encodes amounts in its transfer messages. Wrapping these amounts
+/// prevents mixing them up with native (mint decimals) amounts.
+pub struct NormalizedAmount(u64);
+
+impl NormalizedAmount {
+    pub fn new(amount: u64) -> Self {
+        Self(amount)
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// Normalize raw amount based on this native mint's decimals.
+    pub fn from_native(amount: u64, mint_decimals: u8) -> Result<Self> {
+        checked_normalize_amount(amount, mint_decimals)
+    }
+
+    /// Denormalize this amount based on this native mint's decimals.
+    pub fn to_native(self, mint_decimals: u8) -> Result<u64> {
+        checked_denormalize_amount(self, mint_decimals)
+    }
+}
+
+impl From<NormalizedAmount> for u64 {
+    fn from(amount: NormalizedAmount) -> Self {
+        amount.0
+    }
+}
+
 /// Normalize raw amount based on this native mint's decimals.
+///
+/// NOTE: This panics if the mint decimals are too large. Prefer
+/// [`checked_normalize_amount`].
 pub fn normalize_amount(amount: u64, mint_decimals: u8) -> u64 {
     amount / amount_adjustment(mint_decimals)
 }
@@ -8,16 +49,54 @@ pub fn normalize_amount(amount: u64, mint_decimals: u8) -> u64 {
 /// Denormalize encoded amount based on this native mint's decimals. This will
 /// be the amount transferred from the Token Bridge program's token (custody)
 /// account.
+///
+/// NOTE: This panics on overflow. Prefer [`checked_denormalize_amount`].
 pub fn denormalize_amount(amount: u64, mint_decimals: u8) -> u64 {
     amount * amount_adjustment(mint_decimals)
 }
 
 // Truncate raw amount based on this native mint's decimals before bridging
 /// to prevent dust left in a token account.
+///
+/// NOTE: This panics on overflow. Prefer [`checked_truncate_amount`].
 pub fn truncate_amount(amount: u64, mint_decimals: u8) -> u64 {
     denormalize_amount(normalize_amount(amount, mint_decimals), mint_decimals)
 }
 
+/// Normalize raw amount based on this native mint's decimals.
+pub fn checked_normalize_amount(amount: u64, mint_decimals: u8) -> Result<NormalizedAmount> {
+    Ok(NormalizedAmount(
+        amount / checked_amount_adjustment(mint_decimals)?,
+    ))
+}
+
+/// Denormalize encoded amount based on this native mint's decimals. This will
+/// be the amount transferred from the Token Bridge program's token (custody)
+/// account.
+pub fn checked_denormalize_amount(amount: NormalizedAmount, mint_decimals: u8) -> Result<u64> {
+    amount
+        .0
+        .checked_mul(checked_amount_adjustment(mint_decimals)?)
+        .ok_or(error!(SdkError::AmountOverflow))
+}
+
+/// Truncate raw amount based on this native mint's decimals before bridging
+/// to prevent dust left in a token account.
+pub fn checked_truncate_amount(amount: u64, mint_decimals: u8) -> Result<u64> {
+    checked_denormalize_amount(
+        checked_normalize_amount(amount, mint_decimals)?,
+        mint_decimals,
+    )
+}
+
+/// Compute the dust (residual amount) that will not be bridged because the
+/// Token Bridge program truncates amounts to 8 decimals. Callers can refund or
+/// report this amount.
+pub fn dust_amount(amount: u64, mint_decimals: u8) -> Result<u64> {
+    // Truncated amount is never greater than the raw amount.
+    Ok(amount - checked_truncate_amount(amount, mint_decimals)?)
+}
+
 fn amount_adjustment(mint_decimals: u8) -> u64 {
     if mint_decimals > MAX_WRAPPED_ASSET_DECIMALS {
         10u64.pow((mint_decimals - MAX_WRAPPED_ASSET_DECIMALS) as u32)
@@ -25,3 +104,46 @@ fn amount_adjustment(mint_decimals: u8) -> u64 {
         1
     }
 }
+
+fn checked_amount_adjustment(mint_decimals: u8) -> Result<u64> {
+    if mint_decimals > MAX_WRAPPED_ASSET_DECIMALS {
+        10u64
+            .checked_pow((mint_decimals - MAX_WRAPPED_ASSET_DECIMALS) as u32)
+            .ok_or(error!(SdkError::DecimalsOverflow))
+    } else {
+        Ok(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checked_amount_roundtrip() {
+        let amount = 1_234_567_891_234;
+
+        let normalized = checked_normalize_amount(amount, 12).unwrap();
+        assert_eq!(normalized, NormalizedAmount::new(123_456_789));
+        assert_eq!(normalized.to_native(12).unwrap(), 1_234_567_890_000);
+        assert_eq!(
+            checked_truncate_amount(amount, 12).unwrap(),
+            1_234_567_890_000
+        );
+        assert_eq!(dust_amount(amount, 12).unwrap(), 1_234);
+
+        // Mints with 8 decimals or fewer are not adjusted.
+        assert_eq!(
+            checked_normalize_amount(amount, 6).unwrap(),
+            NormalizedAmount::new(amount)
+        );
+        assert_eq!(dust_amount(amount, 6).unwrap(), 0);
+    }
+
+    #[test]
+    fn checked_amount_overflow() {
+        assert!(checked_denormalize_amount(NormalizedAmount::new(u64::MAX), 9).is_err());
+        assert!(checked_normalize_amount(1, u8::MAX).is_err());
+        assert!(dust_amount(1, u8::MAX).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use crate::wormhole::message::PostedVaaMeta;
 
 use super::program::Wormhole;
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub struct BridgeData {
     /// The current guardian set index, used to decide which signature sets to accept.
     pub guardian_set_index: u32,
@@ -53,7 +53,7 @@ impl Owner for BridgeData {
     }
 }
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub struct FeeCollector {}
 
 impl FeeCollector {
@@ -74,7 +74,7 @@ impl Owner for FeeCollector {
     }
 }
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub struct SequenceTracker {
     pub sequence: u64,
 }
@@ -191,12 +191,29 @@ impl Owner for PostedVaaData {
     }
 }
 
-#[derive(Debug, Default, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct PostedVaa<D: AnchorDeserialize + AnchorSerialize> {
     pub meta: PostedVaaMeta,
     pub payload: (u32, D),
 }
 
+impl<D: AnchorDeserialize + AnchorSerialize> AnchorDeserialize for PostedVaa<D> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let meta = PostedVaaMeta::deserialize_reader(reader)?;
+        let len = u32::deserialize_reader(reader)?;
+
+        // The payload is bounded by its length, so that payloads which read to
+        // the end (e.g. the Token Bridge's `FalliblePayload`) do not pick up
+        // trailing account data.
+        let data = D::deserialize_reader(&mut std::io::Read::take(reader, len.into()))?;
+
+        Ok(Self {
+            meta,
+            payload: (len, data),
+        })
+    }
+}
+
 impl<D: AnchorDeserialize + AnchorSerialize> PostedVaa<D> {
     pub fn version(&self) -> u8 {
         self.meta.version
@@ -3,13 +3,24 @@ use wormhole_anchor_sdk::wormhole::{self, program::Wormhole};
 
 use crate::{
     error::HelloWorldError,
+    governance::GovernanceMessage,
     message::HelloWorldMessage,
-    state::{Config, ForeignEmitter, Received, WormholeEmitter},
+    state::{
+        Acknowledged, AllowedSender, Config, Executed, ForeignEmitter, RateLimit, Reassembly,
+        Received, Treasury, WormholeEmitter,
+    },
 };
 
 /// AKA `b"sent"`.
 pub const SEED_PREFIX_SENT: &[u8; 4] = b"sent";
 
+/// AKA `b"ack"`.
+pub const SEED_PREFIX_ACK: &[u8; 3] = b"ack";
+
+/// AKA `b"sender"`. Seed of the PDA that another program signs with to send
+/// messages through this program via CPI.
+pub const SEED_PREFIX_SENDER: &[u8; 6] = b"sender";
+
 #[derive(Accounts)]
 /// Context used to initialize program data (i.e. config).
 pub struct Initialize<'info> {
@@ -32,6 +43,18 @@ pub struct Initialize<'info> {
     /// as the program's owner.
     pub config: Account<'info, Config>,
 
+    #[account(
+        init,
+        payer = owner,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+        space = Treasury::MAXIMUM_SIZE
+    )]
+    /// Treasury, which receives the fees paid by senders. It is created here
+    /// so that [`send_message`](crate::send_message) does not need to create
+    /// it.
+    pub treasury: Account<'info, Treasury>,
+
     /// Wormhole program.
     pub wormhole_program: Program<'info, Wormhole>,
 
@@ -92,7 +115,11 @@ pub struct Initialize<'info> {
         bump,
     )]
     /// CHECK: Wormhole message account. The Wormhole program writes to this
-    /// account, which requires this program's signature.
+    /// account, which requires this program's signature. Unlike
+    /// [`SendMessage`], this account is derived from the initial sequence
+    /// instead of a payer and nonce. `initialize` posts the only message with
+    /// these seeds (exactly once), and their length differs from the seeds of
+    /// sent messages, so the addresses cannot collide.
     /// [`wormhole::post_message`] requires this account be mutable.
     pub wormhole_message: UncheckedAccount<'info>,
 
@@ -107,7 +134,171 @@ pub struct Initialize<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(chain: u16)]
+/// Context used for owner-only instructions that only modify the config.
+pub struct OwnerOnly<'info> {
+    /// Owner of the program set in the [`Config`] account.
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Mutable.
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SetRateLimits<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Signer for creating
+    /// the global [`RateLimit`] account.
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Mutable.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [RateLimit::SEED_PREFIX],
+        bump,
+        space = RateLimit::MAXIMUM_SIZE
+    )]
+    /// Token bucket shared by every sender. Created here so that senders do
+    /// not need to create it.
+    pub global_rate_limit: Account<'info, RateLimit>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    /// Pending owner set in the [`Config`] account by
+    /// [`propose_owner`](crate::propose_owner).
+    pub pending_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = config.pending_owner == Some(pending_owner.key()) @ HelloWorldError::NotPendingOwner,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. The `pending_owner` specified in the context must equal
+    /// the pending owner specified in this account. Mutable.
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Pays rent for the
+    /// additional space.
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// CHECK: Legacy config account, which cannot be deserialized as
+    /// [`Config`] until it is resized. [`migrate_config`](crate::migrate_config)
+    /// checks its size and owner.
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [Treasury::SEED_PREFIX],
+        bump,
+        space = Treasury::MAXIMUM_SIZE
+    )]
+    /// Treasury, which receives the fees paid by senders. Programs initialized
+    /// before fees were added create it here.
+    pub treasury: Account<'info, Treasury>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(sender: Pubkey)]
+pub struct AddAllowedSender<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Signer for creating
+    /// the [`AllowedSender`] account.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            AllowedSender::SEED_PREFIX,
+            sender.as_ref()
+        ],
+        bump,
+        space = AllowedSender::MAXIMUM_SIZE
+    )]
+    /// Allowed sender account. Created for the allowed sender.
+    pub allowed_sender: Account<'info, AllowedSender>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(sender: Pubkey)]
+pub struct RemoveAllowedSender<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Receives the rent
+    /// of the closed [`AllowedSender`] account.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            AllowedSender::SEED_PREFIX,
+            sender.as_ref()
+        ],
+        bump
+    )]
+    /// Allowed sender account. Closed, so the sender cannot send messages
+    /// while the allowlist is enabled.
+    pub allowed_sender: Account<'info, AllowedSender>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain: u16, address: [u8; 32])]
 pub struct RegisterEmitter<'info> {
     #[account(mut)]
     /// Owner of the program set in the [`Config`] account. Signer for creating
@@ -126,36 +317,240 @@ pub struct RegisterEmitter<'info> {
     #[account(
         init_if_needed,
         payer = owner,
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &chain.to_le_bytes()[..],
+            &address[..]
+        ],
+        bump,
+        space = ForeignEmitter::MAXIMUM_SIZE
+    )]
+    /// Foreign Emitter account. Create this account if this emitter has not
+    /// been registered yet for this Wormhole chain ID. Other emitters
+    /// registered for the same chain are unaffected.
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain: u16, address: [u8; 32])]
+pub struct DeregisterEmitter<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Receives the rent
+    /// of the closed [`ForeignEmitter`] account.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &chain.to_le_bytes()[..],
+            &address[..]
+        ],
+        bump
+    )]
+    /// Foreign Emitter account. This account is closed, so messages from this
+    /// emitter are rejected until it is registered again.
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16, address: [u8; 32])]
+/// Context used for owner-only instructions that update a registered
+/// [`ForeignEmitter`].
+pub struct UpdateEmitter<'info> {
+    /// Owner of the program set in the [`Config`] account.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &chain.to_le_bytes()[..],
+            &address[..]
+        ],
+        bump
+    )]
+    /// Foreign Emitter account. Mutable.
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16, address: [u8; 32])]
+pub struct MigrateEmitter<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Pays rent for the
+    /// additional space.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &chain.to_le_bytes()[..],
+            &address[..]
+        ],
+        bump
+    )]
+    /// CHECK: Legacy foreign emitter account, which cannot be deserialized as
+    /// [`ForeignEmitter`] until it is resized.
+    /// [`migrate_emitter`](crate::migrate_emitter) checks its size and owner.
+    pub foreign_emitter: UncheckedAccount<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16, address: [u8; 32])]
+pub struct MigrateLegacyEmitter<'info> {
+    #[account(mut)]
+    /// Owner of the program set in the [`Config`] account. Pays rent for the
+    /// new [`ForeignEmitter`] account and receives the rent of the legacy one.
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ HelloWorldError::OwnerOnly,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. This program requires that the `owner` specified in the
+    /// context equals the pubkey specified in this account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
         seeds = [
             ForeignEmitter::SEED_PREFIX,
             &chain.to_le_bytes()[..]
         ],
+        bump
+    )]
+    /// CHECK: Foreign emitter account registered when there was one emitter
+    /// per chain, so its seeds do not include the address.
+    /// [`migrate_legacy_emitter`](crate::migrate_legacy_emitter) checks its
+    /// owner and address, then closes it.
+    pub legacy_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &chain.to_le_bytes()[..],
+            &address[..]
+        ],
         bump,
         space = ForeignEmitter::MAXIMUM_SIZE
     )]
-    /// Foreign Emitter account. Create this account if an emitter has not been
-    /// registered yet for this Wormhole chain ID. If there already is an
-    /// emitter address saved in this account, overwrite it.
+    /// Foreign Emitter account replacing the legacy one.
     pub foreign_emitter: Account<'info, ForeignEmitter>,
 
     /// System program.
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(nonce: u64)]
 pub struct SendMessage<'info> {
     #[account(mut)]
     /// Payer will pay Wormhole fee to post a message.
     pub payer: Signer<'info>,
 
+    /// Sender of the message. Usually the payer, or a PDA (derived with
+    /// [`SEED_PREFIX_SENDER`]) signed by a program calling this program.
+    pub sender: Signer<'info>,
+
     #[account(
         seeds = [Config::SEED_PREFIX],
         bump,
+        constraint = !config.pause_outbound @ HelloWorldError::OutboundPaused,
+        constraint = !config.allowlist_enabled || allowed_sender.is_some() @ HelloWorldError::SenderNotAllowed,
+        constraint = !config.global_rate_limit.is_enabled() || global_rate_limit.is_some() @ HelloWorldError::MissingGlobalRateLimit
     )]
     /// Config account. Wormhole PDAs specified in the config are checked
-    /// against the Wormhole accounts in this context. Read-only.
+    /// against the Wormhole accounts in this context. Outbound messages must
+    /// not be paused, and the sender must be allowed if the allowlist is
+    /// enabled. Read-only.
     pub config: Account<'info, Config>,
 
+    #[account(
+        seeds = [
+            AllowedSender::SEED_PREFIX,
+            sender.key().as_ref()
+        ],
+        bump
+    )]
+    /// Sender's allowed sender account. Only required if the allowlist is
+    /// enabled.
+    pub allowed_sender: Option<Account<'info, AllowedSender>>,
+
+    #[account(
+        mut,
+        seeds = [RateLimit::SEED_PREFIX],
+        bump
+    )]
+    /// Token bucket shared by every sender, which is created by
+    /// [`set_rate_limits`](crate::set_rate_limits). Only required if the global
+    /// rate limit is enabled, so that senders do not all write-lock it
+    /// otherwise. Mutable.
+    pub global_rate_limit: Option<Account<'info, RateLimit>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            RateLimit::SEED_PREFIX,
+            sender.key().as_ref()
+        ],
+        bump,
+        space = RateLimit::MAXIMUM_SIZE
+    )]
+    /// Sender's token bucket. Created (at the payer's expense) the first time
+    /// the sender sends a message. Mutable.
+    pub sender_rate_limit: Account<'info, RateLimit>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump
+    )]
+    /// Treasury, which receives the application fee and the fee for
+    /// requesting `Finalized` finality. Created with the config. Mutable.
+    pub treasury: Account<'info, Treasury>,
+
     /// Wormhole program.
     pub wormhole_program: Program<'info, Wormhole>,
 
@@ -194,12 +589,15 @@ pub struct SendMessage<'info> {
         mut,
         seeds = [
             SEED_PREFIX_SENT,
-            &wormhole_sequence.next_value().to_le_bytes()[..]
+            payer.key().as_ref(),
+            &nonce.to_le_bytes()[..]
         ],
         bump,
     )]
-    /// CHECK: Wormhole Message. [`wormhole::post_message`] requires this
-    /// account be mutable.
+    /// CHECK: Wormhole Message. This account is derived from the payer and a
+    /// nonce chosen by the payer (instead of the emitter's next sequence), so
+    /// concurrent senders do not contend for the same account.
+    /// [`wormhole::post_message`] requires this account be mutable.
     pub wormhole_message: UncheckedAccount<'info>,
 
     /// System program.
@@ -214,6 +612,7 @@ pub struct SendMessage<'info> {
 
 type HelloWorldVaa = wormhole::PostedVaa<HelloWorldMessage>;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(vaa_hash: [u8; 32])]
 pub struct ReceiveMessage<'info> {
@@ -224,9 +623,13 @@ pub struct ReceiveMessage<'info> {
     #[account(
         seeds = [Config::SEED_PREFIX],
         bump,
+        constraint = !config.pause_inbound @ HelloWorldError::InboundPaused
     )]
     /// Config account. Wormhole PDAs specified in the config are checked
-    /// against the Wormhole accounts in this context. Read-only.
+    /// against the Wormhole accounts in this context. Inbound messages must
+    /// not be paused. Since the [`Received`] account is only created when a
+    /// message is received, a message rejected while paused can be received
+    /// after unpausing. Read-only.
     pub config: Account<'info, Config>,
 
     // Wormhole program.
@@ -245,16 +648,20 @@ pub struct ReceiveMessage<'info> {
     pub posted: Account<'info, HelloWorldVaa>,
 
     #[account(
+        mut,
         seeds = [
             ForeignEmitter::SEED_PREFIX,
-            &posted.emitter_chain().to_le_bytes()[..]
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.emitter_address()[..]
         ],
         bump,
-        constraint = foreign_emitter.verify(posted.emitter_address()) @ HelloWorldError::InvalidForeignEmitter
+        constraint = foreign_emitter.verify(posted.emitter_address()) @ HelloWorldError::InvalidForeignEmitter,
+        constraint = foreign_emitter.enabled @ HelloWorldError::ForeignEmitterDisabled
     )]
-    /// Foreign emitter account. The posted message's `emitter_address` must
-    /// agree with the one we have registered for this message's `emitter_chain`
-    /// (chain ID). Read-only.
+    /// Foreign emitter account. The posted message's `emitter_address` must be
+    /// one of the emitters registered for this message's `emitter_chain`
+    /// (chain ID), and the emitter must be enabled. Mutable, since the next
+    /// expected sequence is updated in ordered mode.
     pub foreign_emitter: Account<'info, ForeignEmitter>,
 
     #[account(
@@ -263,6 +670,7 @@ pub struct ReceiveMessage<'info> {
         seeds = [
             Received::SEED_PREFIX,
             &posted.emitter_chain().to_le_bytes()[..],
+            &posted.emitter_address()[..],
             &posted.sequence().to_le_bytes()[..]
         ],
         bump,
@@ -271,9 +679,265 @@ pub struct ReceiveMessage<'info> {
     /// Received account. [`receive_message`](crate::receive_message) will
     /// deserialize the Wormhole message's payload and save it to this account.
     /// This account cannot be overwritten, and will prevent Wormhole message
-    /// replay with the same sequence.
+    /// replay with the same emitter and sequence.
     pub received: Account<'info, Received>,
 
+    #[account(
+        seeds = [
+            Received::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.sequence().to_le_bytes()[..]
+        ],
+        bump,
+        constraint = legacy_received.data_is_empty() @ HelloWorldError::AlreadyReceived
+    )]
+    /// CHECK: Received account from when its seeds did not include the emitter
+    /// address. It must not exist, so that Wormhole messages received before
+    /// the seeds changed cannot be replayed. Read-only.
+    pub legacy_received: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            Acknowledged::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.emitter_address()[..],
+            &posted.data().ack_sequence().unwrap_or_default().to_le_bytes()[..]
+        ],
+        bump,
+        space = Acknowledged::MAXIMUM_SIZE
+    )]
+    /// Acknowledged account. Only provided if the Wormhole message is an
+    /// `Ack`, in which case [`receive_message`](crate::receive_message) records
+    /// that the foreign emitter received the message with the acknowledged
+    /// sequence.
+    pub acknowledged: Option<Account<'info, Acknowledged>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            Reassembly::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.emitter_address()[..],
+            &posted.data().chunk_message_id().unwrap_or_default().to_le_bytes()[..]
+        ],
+        bump,
+        space = Reassembly::MAXIMUM_SIZE
+    )]
+    /// Reassembly account. Only provided if the Wormhole message is a
+    /// `Chunk`, in which case [`receive_message`](crate::receive_message)
+    /// writes the chunk here. Created by whoever receives the first chunk.
+    /// Once closed, it is a tombstone whose size fails this constraint, so
+    /// chunks with its message ID cannot be received again.
+    pub reassembly: Option<Account<'info, Reassembly>>,
+
+    #[account(
+        mut,
+        address = config.wormhole.bridge @ HelloWorldError::InvalidWormholeConfig
+    )]
+    /// Wormhole bridge data. [`wormhole::post_message`] requires this account
+    /// be mutable.
+    pub wormhole_bridge: Account<'info, wormhole::BridgeData>,
+
+    #[account(
+        mut,
+        address = config.wormhole.fee_collector @ HelloWorldError::InvalidWormholeFeeCollector
+    )]
+    /// Wormhole fee collector. [`wormhole::post_message`] requires this
+    /// account be mutable.
+    pub wormhole_fee_collector: Account<'info, wormhole::FeeCollector>,
+
+    #[account(
+        seeds = [WormholeEmitter::SEED_PREFIX],
+        bump,
+    )]
+    /// Program's emitter account. Read-only.
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    #[account(
+        mut,
+        address = config.wormhole.sequence @ HelloWorldError::InvalidWormholeSequence
+    )]
+    /// Emitter's sequence account. [`wormhole::post_message`] requires this
+    /// account be mutable.
+    pub wormhole_sequence: Account<'info, wormhole::SequenceTracker>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX_ACK,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.emitter_address()[..],
+            &posted.sequence().to_le_bytes()[..]
+        ],
+        bump,
+    )]
+    /// CHECK: Wormhole Message for the `Ack`. This account is only written to
+    /// if the foreign emitter requested acknowledgements.
+    /// [`wormhole::post_message`] requires this account be mutable.
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar.
+    pub clock: Sysvar<'info, Clock>,
+
+    /// Rent sysvar.
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReceived<'info> {
+    #[account(mut)]
+    /// Payer of the [`Received`] account's rent. Receives the reclaimed rent.
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !received.is_legacy() @ HelloWorldError::LegacyReceived,
+        constraint = received.payer == payer.key() @ HelloWorldError::PayerOnly,
+        realloc = Received::TOMBSTONE_SIZE,
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    /// Received account. This account is shrunk to a tombstone instead of
+    /// being closed, which would allow the Wormhole message to be replayed.
+    /// Legacy accounts, which do not record their payer, cannot be shrunk.
+    pub received: Account<'info, Received>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReassembly<'info> {
+    /// Payer of the [`Reassembly`] account's rent to close a complete
+    /// reassembly, or anyone to close an abandoned one.
+    pub closer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Payer of the [`Reassembly`] account's rent, who receives the
+    /// reclaimed rent.
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = payer @ HelloWorldError::PayerOnly,
+        constraint = !reassembly.closed @ HelloWorldError::ReassemblyClosed,
+        realloc = Reassembly::TOMBSTONE_SIZE,
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    /// Reassembly account. Closed once its message is processed or once it is
+    /// abandoned. This account is shrunk to a tombstone instead of being
+    /// closed, which would allow chunks with its message ID to be received
+    /// again.
+    pub reassembly: Account<'info, Reassembly>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    /// Config account. Read-only.
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump
+    )]
+    /// Treasury holding the fees paid by senders. Mutable.
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        address = config.fee_recipient @ HelloWorldError::InvalidFeeRecipient
+    )]
+    /// CHECK: Fee recipient set in the [`Config`] account. Mutable.
+    pub fee_recipient: UncheckedAccount<'info>,
+}
+
+type GovernanceVaa = wormhole::PostedVaa<GovernanceMessage>;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ExecuteGovernance<'info> {
+    #[account(mut)]
+    /// Payer will initialize the [`Executed`] account (and the
+    /// [`ForeignEmitter`] account of a `RegisterEmitter` action).
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        constraint = config.is_governance_emitter(
+            posted.emitter_chain(),
+            posted.emitter_address()
+        ) @ HelloWorldError::InvalidGovernanceEmitter
+    )]
+    /// Config account. The posted message must be emitted by the governance
+    /// emitter specified in this account. Governance VAAs are executed even
+    /// when inbound messages are paused. Mutable.
+    pub config: Account<'info, Config>,
+
+    // Wormhole program.
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    #[account(
+        seeds = [
+            wormhole::SEED_PREFIX_POSTED_VAA,
+            &vaa_hash
+        ],
+        bump,
+        seeds::program = wormhole_program.key
+    )]
+    /// Verified governance Wormhole message account. The Wormhole program
+    /// verified signatures and posted the account data here. Read-only.
+    pub posted: Account<'info, GovernanceVaa>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            Executed::SEED_PREFIX,
+            &posted.emitter_chain().to_le_bytes()[..],
+            &posted.emitter_address()[..],
+            &posted.sequence().to_le_bytes()[..]
+        ],
+        bump,
+        space = Executed::MAXIMUM_SIZE
+    )]
+    /// Executed account. This account cannot be overwritten, and will prevent
+    /// governance VAA replay with the same emitter and sequence.
+    pub executed: Account<'info, Executed>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &posted.data().emitter_to_register().unwrap_or_default().0.to_le_bytes()[..],
+            &posted.data().emitter_to_register().unwrap_or_default().1[..]
+        ],
+        bump,
+        space = ForeignEmitter::MAXIMUM_SIZE
+    )]
+    /// Foreign Emitter account. Only provided if the governance action is
+    /// `RegisterEmitter`, in which case it is created if this emitter has not
+    /// been registered yet.
+    pub foreign_emitter: Option<Account<'info, ForeignEmitter>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 }
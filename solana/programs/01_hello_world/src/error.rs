@@ -38,4 +38,138 @@ pub enum HelloWorldError {
     #[msg("InvalidMessage")]
     /// Deserialized message has unexpected payload type.
     InvalidMessage,
+
+    #[msg("ForeignEmitterDisabled")]
+    /// Foreign emitter is registered but disabled by the owner.
+    ForeignEmitterDisabled,
+
+    #[msg("InvalidNewOwner")]
+    /// Proposed owner cannot be the default pubkey or the current owner.
+    InvalidNewOwner,
+
+    #[msg("NotPendingOwner")]
+    /// Only the pending owner can accept ownership.
+    NotPendingOwner,
+
+    #[msg("NoPendingOwner")]
+    /// There is no ownership transfer to cancel.
+    NoPendingOwner,
+
+    #[msg("ConfigAlreadyMigrated")]
+    /// Config account already has the current size.
+    ConfigAlreadyMigrated,
+
+    #[msg("OutboundPaused")]
+    /// Sending messages is paused by the owner.
+    OutboundPaused,
+
+    #[msg("InboundPaused")]
+    /// Receiving messages is paused by the owner.
+    InboundPaused,
+
+    #[msg("PayerOnly")]
+    /// Only the payer of the Received account is permitted.
+    PayerOnly,
+
+    #[msg("InvalidFinality")]
+    /// Finality is not a valid Wormhole finality.
+    InvalidFinality,
+
+    #[msg("InvalidFeeRecipient")]
+    /// Fee recipient is not the expected account, or cannot be the default
+    /// pubkey.
+    InvalidFeeRecipient,
+
+    #[msg("OutOfOrderMessage")]
+    /// Message sequence is not the ordered foreign emitter's next expected
+    /// sequence.
+    OutOfOrderMessage,
+
+    #[msg("EmitterNotOrdered")]
+    /// Foreign emitter does not have ordered delivery enabled.
+    EmitterNotOrdered,
+
+    #[msg("InvalidSequenceSkip")]
+    /// Sequence to skip to must be greater than the next expected sequence.
+    InvalidSequenceSkip,
+
+    #[msg("InvalidAck")]
+    /// Acknowledged message was not sent from Solana.
+    InvalidAck,
+
+    #[msg("InvalidAcknowledged")]
+    /// Acknowledged account must be provided for `Ack` messages only.
+    InvalidAcknowledged,
+
+    #[msg("InvalidChunk")]
+    /// Chunk has a bad index, total or length, or does not match the chunks
+    /// already received for its message ID.
+    InvalidChunk,
+
+    #[msg("ReassemblyInProgress")]
+    /// Reassembly is unfinished and has not been abandoned, or is finished
+    /// and the signer is not its payer.
+    ReassemblyInProgress,
+
+    #[msg("InvalidRateLimit")]
+    /// Rate limit with a nonzero capacity must have a nonzero refill period.
+    InvalidRateLimit,
+
+    #[msg("RateLimited")]
+    /// Sender or global rate limit is exhausted. The error message includes
+    /// the seconds until the next allowed send.
+    RateLimited,
+
+    #[msg("NoFeesToWithdraw")]
+    /// Treasury has no lamports above its rent-exempt minimum.
+    NoFeesToWithdraw,
+
+    #[msg("UntimelyMessage")]
+    /// Message timestamp is older than the foreign emitter's maximum message
+    /// age, or too far in the future.
+    UntimelyMessage,
+
+    #[msg("SenderNotAllowed")]
+    /// Allowlist is enabled and the sender is not an allowed sender.
+    SenderNotAllowed,
+
+    #[msg("InvalidSender")]
+    /// Allowed sender is not the sender PDA of the specified program.
+    InvalidSender,
+
+    #[msg("InvalidGovernanceEmitter")]
+    /// Governance is disabled, or the governance VAA was not emitted by the
+    /// governance emitter set in the config.
+    InvalidGovernanceEmitter,
+
+    #[msg("InvalidTargetChain")]
+    /// Governance VAA targets another chain.
+    InvalidTargetChain,
+
+    #[msg("EmitterAlreadyMigrated")]
+    /// Foreign emitter account already has the current size.
+    EmitterAlreadyMigrated,
+
+    #[msg("AlreadyReceived")]
+    /// Wormhole message was received before the emitter address was added to
+    /// the [`Received`](crate::state::Received) account seeds.
+    AlreadyReceived,
+
+    #[msg("LegacyReceived")]
+    /// Received account was created before its payer was recorded, so its
+    /// rent cannot be reclaimed.
+    LegacyReceived,
+
+    #[msg("SequenceOverflow")]
+    /// Message with the maximum sequence was received in ordered mode, so
+    /// there is no next expected sequence.
+    SequenceOverflow,
+
+    #[msg("ReassemblyClosed")]
+    /// Reassembly was already closed.
+    ReassemblyClosed,
+
+    #[msg("MissingGlobalRateLimit")]
+    /// Global rate limit is enabled, but its token bucket was not provided.
+    MissingGlobalRateLimit,
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+#[event]
+/// Emitted when this program posts a Wormhole message.
+pub struct MessageSent {
+    /// Wormhole sequence of the posted message.
+    pub sequence: u64,
+    /// Keccak256 hash of the message payload.
+    pub payload_hash: [u8; 32],
+    /// Payer of the Wormhole fee.
+    pub payer: Pubkey,
+}
+
+#[event]
+/// Emitted when [`receive_message`](crate::receive_message) receives a
+/// verified Wormhole message.
+pub struct MessageReceived {
+    /// Wormhole chain ID of the foreign emitter.
+    pub emitter_chain: u16,
+    /// Address of the foreign emitter.
+    pub emitter_address: [u8; 32],
+    /// Wormhole sequence of the received message.
+    pub sequence: u64,
+    /// Keccak256 hash of the verified Wormhole message.
+    pub vaa_hash: [u8; 32],
+}
+
+#[event]
+/// Emitted when a foreign emitter is registered.
+pub struct EmitterRegistered {
+    /// Wormhole chain ID of the foreign emitter.
+    pub chain: u16,
+    /// Address of the foreign emitter.
+    pub address: [u8; 32],
+}
+
+#[event]
+/// Emitted when a foreign emitter is deregistered.
+pub struct EmitterDeregistered {
+    /// Wormhole chain ID of the foreign emitter.
+    pub chain: u16,
+    /// Address of the foreign emitter.
+    pub address: [u8; 32],
+}
@@ -0,0 +1,252 @@
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, AnchorSerialize};
+use std::io;
+use wormhole_io::Readable;
+
+const ACTION_REGISTER_EMITTER: u8 = 1;
+const ACTION_SET_FEE: u8 = 2;
+const ACTION_PAUSE: u8 = 3;
+const ACTION_TRANSFER_OWNERSHIP: u8 = 4;
+
+/// AKA `b"HelloWorld"`, left-padded with zeros. Every governance payload
+/// starts with this module so that it cannot be mistaken for another
+/// application's governance payload.
+pub const GOVERNANCE_MODULE: [u8; 32] = {
+    let name = b"HelloWorld";
+    let mut module = [0; 32];
+    let mut i = 0;
+    while i < name.len() {
+        module[32 - name.len() + i] = name[i];
+        i += 1;
+    }
+    module
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Admin actions that the governance emitter can execute with
+/// [`execute_governance`](crate::execute_governance). Each action does the
+/// same as the owner-only instruction of the same purpose.
+/// * `RegisterEmitter`: Action == 1. Same as
+///   [`register_emitter`](crate::register_emitter).
+/// * `SetFee`: Action == 2. Same as
+///   [`update_fee_settings`](crate::update_fee_settings).
+/// * `Pause`: Action == 3. Same as
+///   [`set_pause_outbound`](crate::set_pause_outbound) and
+///   [`set_pause_inbound`](crate::set_pause_inbound).
+/// * `TransferOwnership`: Action == 4. Transfers ownership immediately and
+///   cancels any pending ownership transfer.
+///
+/// Actions are encoded as u8.
+pub enum GovernanceAction {
+    RegisterEmitter { chain: u16, address: [u8; 32] },
+    SetFee { app_fee: u64, fee_recipient: Pubkey },
+    Pause { outbound: bool, inbound: bool },
+    TransferOwnership { new_owner: Pubkey },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Governance payload. Encoded as [`GOVERNANCE_MODULE`], the action (u8), the
+/// target chain and the action's arguments. Integers are big endian.
+pub struct GovernanceMessage {
+    /// Wormhole chain ID of the chain that should execute this action.
+    pub target_chain: u16,
+    pub action: GovernanceAction,
+}
+
+impl GovernanceMessage {
+    /// Chain and address of the emitter to register if this is a
+    /// `RegisterEmitter` action.
+    pub fn emitter_to_register(&self) -> Option<(u16, [u8; 32])> {
+        match self.action {
+            GovernanceAction::RegisterEmitter { chain, address } => Some((chain, address)),
+            _ => None,
+        }
+    }
+}
+
+impl AnchorSerialize for GovernanceMessage {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        GOVERNANCE_MODULE.serialize(writer)?;
+        match &self.action {
+            GovernanceAction::RegisterEmitter { chain, address } => {
+                ACTION_REGISTER_EMITTER.serialize(writer)?;
+                self.target_chain.to_be_bytes().serialize(writer)?;
+                chain.to_be_bytes().serialize(writer)?;
+                address.serialize(writer)
+            }
+            GovernanceAction::SetFee {
+                app_fee,
+                fee_recipient,
+            } => {
+                ACTION_SET_FEE.serialize(writer)?;
+                self.target_chain.to_be_bytes().serialize(writer)?;
+                app_fee.to_be_bytes().serialize(writer)?;
+                fee_recipient.serialize(writer)
+            }
+            GovernanceAction::Pause { outbound, inbound } => {
+                ACTION_PAUSE.serialize(writer)?;
+                self.target_chain.to_be_bytes().serialize(writer)?;
+                (*outbound as u8).serialize(writer)?;
+                (*inbound as u8).serialize(writer)
+            }
+            GovernanceAction::TransferOwnership { new_owner } => {
+                ACTION_TRANSFER_OWNERSHIP.serialize(writer)?;
+                self.target_chain.to_be_bytes().serialize(writer)?;
+                new_owner.serialize(writer)
+            }
+        }
+    }
+}
+
+impl AnchorDeserialize for GovernanceMessage {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        if <[u8; 32]>::read(reader)? != GOVERNANCE_MODULE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid governance module",
+            ));
+        }
+
+        let action_id = u8::read(reader)?;
+        let target_chain = u16::read(reader)?;
+        let action = match action_id {
+            ACTION_REGISTER_EMITTER => GovernanceAction::RegisterEmitter {
+                chain: u16::read(reader)?,
+                address: <[u8; 32]>::read(reader)?,
+            },
+            ACTION_SET_FEE => GovernanceAction::SetFee {
+                app_fee: u64::read(reader)?,
+                fee_recipient: Pubkey::from(<[u8; 32]>::read(reader)?),
+            },
+            ACTION_PAUSE => GovernanceAction::Pause {
+                outbound: u8::read(reader)? != 0,
+                inbound: u8::read(reader)? != 0,
+            },
+            ACTION_TRANSFER_OWNERSHIP => GovernanceAction::TransferOwnership {
+                new_owner: Pubkey::from(<[u8; 32]>::read(reader)?),
+            },
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid governance action",
+                ))
+            }
+        };
+
+        Ok(GovernanceMessage {
+            target_chain,
+            action,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anchor_lang::prelude::Result;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_governance_module() {
+        assert_eq!(GOVERNANCE_MODULE[..22], [0; 22]);
+        assert_eq!(&GOVERNANCE_MODULE[22..], b"HelloWorld");
+    }
+
+    #[test]
+    fn test_governance_register_emitter() -> Result<()> {
+        let msg = GovernanceMessage {
+            target_chain: 1,
+            action: GovernanceAction::RegisterEmitter {
+                chain: 2,
+                address: [69; 32],
+            },
+        };
+
+        // Serialize message above.
+        let mut encoded = Vec::new();
+        msg.serialize(&mut encoded)?;
+
+        assert_eq!(
+            encoded.len(),
+            size_of::<[u8; 32]>()
+                + size_of::<u8>()
+                + size_of::<u16>()
+                + size_of::<u16>()
+                + size_of::<[u8; 32]>()
+        );
+
+        // Verify module, action and target chain (big endian).
+        assert_eq!(encoded[..32], GOVERNANCE_MODULE);
+        assert_eq!(encoded[32], ACTION_REGISTER_EMITTER);
+        assert_eq!(encoded[33..35], 1u16.to_be_bytes());
+
+        // Verify emitter chain (big endian) and address.
+        assert_eq!(encoded[35..37], 2u16.to_be_bytes());
+        assert_eq!(encoded[37..], [69; 32]);
+
+        // Now deserialize the encoded message.
+        let decoded = GovernanceMessage::deserialize(&mut encoded.as_slice())?;
+        assert_eq!(decoded.emitter_to_register(), Some((2, [69; 32])));
+        assert_eq!(decoded, msg);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_governance_actions() -> Result<()> {
+        let actions = [
+            GovernanceAction::SetFee {
+                app_fee: 420,
+                fee_recipient: Pubkey::new_unique(),
+            },
+            GovernanceAction::Pause {
+                outbound: true,
+                inbound: false,
+            },
+            GovernanceAction::TransferOwnership {
+                new_owner: Pubkey::new_unique(),
+            },
+        ];
+        for action in actions {
+            let msg = GovernanceMessage {
+                target_chain: 1,
+                action,
+            };
+
+            let mut encoded = Vec::new();
+            msg.serialize(&mut encoded)?;
+
+            let decoded = GovernanceMessage::deserialize(&mut encoded.as_slice())?;
+            assert!(decoded.emitter_to_register().is_none());
+            assert_eq!(decoded, msg);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_governance_invalid() -> Result<()> {
+        let msg = GovernanceMessage {
+            target_chain: 1,
+            action: GovernanceAction::Pause {
+                outbound: true,
+                inbound: true,
+            },
+        };
+        let mut encoded = Vec::new();
+        msg.serialize(&mut encoded)?;
+
+        // Another application's module.
+        let mut other_module = encoded.clone();
+        other_module[31] = b'!';
+        let err = GovernanceMessage::deserialize(&mut other_module.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        // Unknown action.
+        let mut unknown_action = encoded.clone();
+        unknown_action[32] = 0;
+        let err = GovernanceMessage::deserialize(&mut unknown_action.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    }
+}
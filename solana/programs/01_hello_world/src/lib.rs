@@ -2,11 +2,15 @@ use anchor_lang::prelude::*;
 
 pub use context::*;
 pub use error::*;
+pub use event::*;
+pub use governance::*;
 pub use message::*;
 pub use state::*;
 
 pub mod context;
 pub mod error;
+pub mod event;
+pub mod governance;
 pub mod message;
 pub mod state;
 
@@ -21,14 +25,56 @@ declare_id!("Scaffo1dingHe11oWor1d1111111111111111111111");
 ///
 /// ## Program Instructions
 /// * [`initialize`](initialize)
+/// * [`propose_owner`](propose_owner)
+/// * [`accept_ownership`](accept_ownership)
+/// * [`cancel_ownership_transfer`](cancel_ownership_transfer)
+/// * [`set_pause_outbound`](set_pause_outbound)
+/// * [`set_pause_inbound`](set_pause_inbound)
+/// * [`update_message_settings`](update_message_settings)
+/// * [`set_rate_limits`](set_rate_limits)
+/// * [`update_fee_settings`](update_fee_settings)
+/// * [`withdraw_fees`](withdraw_fees)
+/// * [`set_allowlist_enabled`](set_allowlist_enabled)
+/// * [`add_allowed_sender`](add_allowed_sender)
+/// * [`remove_allowed_sender`](remove_allowed_sender)
+/// * [`set_governance_emitter`](set_governance_emitter)
+/// * [`migrate_config`](migrate_config)
 /// * [`register_emitter`](register_emitter)
+/// * [`deregister_emitter`](deregister_emitter)
+/// * [`set_emitter_enabled`](set_emitter_enabled)
+/// * [`set_emitter_ordered`](set_emitter_ordered)
+/// * [`skip_to_sequence`](skip_to_sequence)
+/// * [`set_emitter_acks`](set_emitter_acks)
+/// * [`set_emitter_message_age`](set_emitter_message_age)
+/// * [`migrate_emitter`](migrate_emitter)
+/// * [`migrate_legacy_emitter`](migrate_legacy_emitter)
 /// * [`send_message`](send_message)
+/// * [`send_message_chunk`](send_message_chunk)
 /// * [`receive_message`](receive_message)
+/// * [`close_received`](close_received)
+/// * [`close_reassembly`](close_reassembly)
+/// * [`execute_governance`](execute_governance)
+///
+/// ## Program Events
+/// * [MessageSent]
+/// * [MessageReceived]
+/// * [EmitterRegistered]
+/// * [EmitterDeregistered]
+///
+/// Events are emitted with `emit_cpi!`, so they can be read from the inner
+/// instructions of a transaction (including when this program is called by
+/// another program).
 ///
 /// ## Program Accounts
+/// * [Acknowledged]
+/// * [AllowedSender]
 /// * [Config]
+/// * [Executed]
 /// * [ForeignEmitter]
+/// * [RateLimit]
+/// * [Reassembly]
 /// * [Received]
+/// * [Treasury]
 /// * [WormholeEmitter]
 pub mod hello_world {
     use super::*;
@@ -37,9 +83,10 @@ pub mod hello_world {
 
     /// This instruction initializes the program config, which is meant
     /// to store data useful for other instructions. The config specifies
-    /// an owner (e.g. multisig) and should be read-only for every instruction
-    /// in this example. This owner will be checked for designated owner-only
-    /// instructions like [`register_emitter`](register_emitter).
+    /// an owner (e.g. multisig), which is checked for designated owner-only
+    /// instructions like [`register_emitter`](register_emitter). Only the
+    /// owner (or the governance emitter with
+    /// [`execute_governance`](execute_governance)) can update the config.
     ///
     /// # Arguments
     ///
@@ -48,7 +95,14 @@ pub mod hello_world {
         let config = &mut ctx.accounts.config;
 
         // Set the owner of the config (effectively the owner of the program).
+        // Ownership can be transferred later with `propose_owner` and
+        // `accept_ownership`.
         config.owner = ctx.accounts.owner.key();
+        config.pending_owner = None;
+
+        // Nothing is paused.
+        config.pause_outbound = false;
+        config.pause_inbound = false;
 
         // Set Wormhole related addresses.
         {
@@ -75,6 +129,27 @@ pub mod hello_world {
         // so this value is stored as u8.
         config.finality = wormhole::Finality::Confirmed as u8;
 
+        // Senders can request `Finalized` finality for free until the owner
+        // sets a fee with `update_message_settings`.
+        config.finalized_fee = 0;
+
+        // Sending is not rate limited until the owner sets limits with
+        // `set_rate_limits`.
+        config.global_rate_limit = RateLimitParams::default();
+        config.sender_rate_limit = RateLimitParams::default();
+
+        // No application fee until the owner sets one with
+        // `update_fee_settings`. Fees are withdrawn to the owner by default.
+        config.app_fee = 0;
+        config.fee_recipient = ctx.accounts.owner.key();
+
+        // Anyone can send messages until the owner enables the allowlist.
+        config.allowlist_enabled = false;
+
+        // Governance is disabled until the owner sets a governance emitter.
+        config.governance_chain = 0;
+        config.governance_emitter = [0; 32];
+
         // Initialize our Wormhole emitter account. It is not required by the
         // Wormhole program that there is an actual account associated with the
         // emitter PDA. The emitter PDA is just a mechanism to have the program
@@ -164,18 +239,364 @@ pub mod hello_world {
                 ),
                 config.batch_id,
                 payload,
-                config.finality.try_into().unwrap(),
+                config
+                    .finality
+                    .try_into()
+                    .map_err(|_| HelloWorldError::InvalidFinality)?,
+            )?;
+        }
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction proposes a new owner of the program. Ownership is not
+    /// transferred until the proposed owner calls
+    /// [`accept_ownership`](accept_ownership), so ownership cannot be
+    /// transferred to a key nobody controls. Proposing another owner replaces
+    /// the pending one. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`       - `OwnerOnly` context
+    /// * `new_owner` - Proposed owner (e.g. multisig)
+    pub fn propose_owner(ctx: Context<OwnerOnly>, new_owner: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(
+            new_owner != Pubkey::default() && new_owner != config.owner,
+            HelloWorldError::InvalidNewOwner
+        );
+
+        config.pending_owner = Some(new_owner);
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction transfers ownership of the program to the pending
+    /// owner proposed by [`propose_owner`](propose_owner). Only the pending
+    /// owner can call this instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `AcceptOwnership` context
+    pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.owner = ctx.accounts.pending_owner.key();
+        config.pending_owner = None;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction cancels an ownership transfer proposed by
+    /// [`propose_owner`](propose_owner). This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `OwnerOnly` context
+    pub fn cancel_ownership_transfer(ctx: Context<OwnerOnly>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(
+            config.pending_owner.is_some(),
+            HelloWorldError::NoPendingOwner
+        );
+
+        config.pending_owner = None;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction pauses or unpauses [`send_message`](send_message).
+    /// This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `OwnerOnly` context
+    /// * `paused` - Whether to reject outbound messages
+    pub fn set_pause_outbound(ctx: Context<OwnerOnly>, paused: bool) -> Result<()> {
+        ctx.accounts.config.pause_outbound = paused;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction pauses or unpauses [`receive_message`](receive_message).
+    /// Messages rejected while paused can be received after unpausing. This
+    /// instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `OwnerOnly` context
+    /// * `paused` - Whether to reject inbound messages
+    pub fn set_pause_inbound(ctx: Context<OwnerOnly>, paused: bool) -> Result<()> {
+        ctx.accounts.config.pause_inbound = paused;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction updates the batch ID and finality used to post
+    /// Wormhole messages, and the fee charged to senders who request
+    /// `Finalized` finality for their message. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`           - `OwnerOnly` context
+    /// * `batch_id`      - Batch ID (AKA nonce) of posted messages
+    /// * `finality`      - u8 representation of [wormhole::Finality]
+    /// * `finalized_fee` - Fee (lamports) for requesting `Finalized` finality
+    pub fn update_message_settings(
+        ctx: Context<OwnerOnly>,
+        batch_id: u32,
+        finality: u8,
+        finalized_fee: u64,
+    ) -> Result<()> {
+        require!(
+            wormhole::Finality::try_from(finality).is_ok(),
+            HelloWorldError::InvalidFinality
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.batch_id = batch_id;
+        config.finality = finality;
+        config.finalized_fee = finalized_fee;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction sets the token buckets that limit how often
+    /// [`send_message`](send_message) and
+    /// [`send_message_chunk`](send_message_chunk) can be called, both by all
+    /// senders together and by each sender. A zero capacity disables a limit.
+    /// The global [RateLimit] account is created if it does not exist yet.
+    /// This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `SetRateLimits` context
+    /// * `global` - Token bucket shared by every sender
+    /// * `sender` - Token bucket of each sender
+    pub fn set_rate_limits(
+        ctx: Context<SetRateLimits>,
+        global: RateLimitParams,
+        sender: RateLimitParams,
+    ) -> Result<()> {
+        require!(
+            [global, sender]
+                .iter()
+                .all(|params| !params.is_enabled() || params.seconds_per_token > 0),
+            HelloWorldError::InvalidRateLimit
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.global_rate_limit = global;
+        config.sender_rate_limit = sender;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction sets the application fee paid by senders for every
+    /// message (see [`send_message`](send_message)) and the recipient of every
+    /// fee withdrawn from the treasury. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`           - `OwnerOnly` context
+    /// * `app_fee`       - Fee (lamports) paid to the treasury per message
+    /// * `fee_recipient` - Recipient of [`withdraw_fees`](withdraw_fees)
+    pub fn update_fee_settings(
+        ctx: Context<OwnerOnly>,
+        app_fee: u64,
+        fee_recipient: Pubkey,
+    ) -> Result<()> {
+        require!(
+            fee_recipient != Pubkey::default(),
+            HelloWorldError::InvalidFeeRecipient
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.app_fee = app_fee;
+        config.fee_recipient = fee_recipient;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction withdraws the fees collected in the treasury (both the
+    /// [Config::app_fee] and the [Config::finalized_fee]) to the fee recipient
+    /// set in the [Config]. The treasury keeps
+    /// its rent-exempt minimum. Since fees can only go to the fee recipient,
+    /// anyone can call this instruction. Returns the withdrawn lamports.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `WithdrawFees` context
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<u64> {
+        let treasury = ctx.accounts.treasury.to_account_info();
+        let amount = treasury
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(treasury.data_len()));
+        require!(amount > 0, HelloWorldError::NoFeesToWithdraw);
+
+        treasury.sub_lamports(amount)?;
+        ctx.accounts.fee_recipient.add_lamports(amount)?;
+
+        // Done.
+        Ok(amount)
+    }
+
+    /// This instruction enables or disables the sender allowlist. While it is
+    /// enabled, only senders added with
+    /// [`add_allowed_sender`](add_allowed_sender) can call
+    /// [`send_message`](send_message). This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `OwnerOnly` context
+    /// * `enabled` - Whether only allowed senders can send messages
+    pub fn set_allowlist_enabled(ctx: Context<OwnerOnly>, enabled: bool) -> Result<()> {
+        ctx.accounts.config.allowlist_enabled = enabled;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction adds a sender to the allowlist. The sender can be a
+    /// wallet, or a PDA that another program signs with to send messages
+    /// through this program via CPI. In the latter case, the program ID is
+    /// specified and the sender must be the program's PDA derived with
+    /// [SEED_PREFIX_SENDER]. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `AddAllowedSender` context
+    /// * `sender`  - Sender's pubkey
+    /// * `program` - Program that signs for the sender PDA, if any
+    pub fn add_allowed_sender(
+        ctx: Context<AddAllowedSender>,
+        sender: Pubkey,
+        program: Option<Pubkey>,
+    ) -> Result<()> {
+        if let Some(program_id) = program {
+            let (expected, _) = Pubkey::find_program_address(&[SEED_PREFIX_SENDER], &program_id);
+            require_keys_eq!(sender, expected, HelloWorldError::InvalidSender);
+        }
+
+        let allowed_sender = &mut ctx.accounts.allowed_sender;
+        allowed_sender.sender = sender;
+        allowed_sender.program = program;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction removes a sender from the allowlist by closing its
+    /// AllowedSender account and refunding its rent to the owner. This
+    /// instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `RemoveAllowedSender` context
+    /// * `sender` - Sender's pubkey
+    pub fn remove_allowed_sender(
+        _ctx: Context<RemoveAllowedSender>,
+        _sender: Pubkey,
+    ) -> Result<()> {
+        // Anchor closes the AllowedSender account.
+        Ok(())
+    }
+
+    /// This instruction sets the governance emitter, whose governance VAAs can
+    /// be executed with [`execute_governance`](execute_governance). Set a zero
+    /// chain to disable governance. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `OwnerOnly` context
+    /// * `chain`   - Wormhole Chain ID of the governance emitter
+    /// * `address` - Wormhole Emitter Address of the governance emitter
+    pub fn set_governance_emitter(
+        ctx: Context<OwnerOnly>,
+        chain: u16,
+        address: [u8; 32],
+    ) -> Result<()> {
+        // Governance can only be disabled with a zero address.
+        require!(
+            (chain == 0) == address.iter().all(|&x| x == 0),
+            HelloWorldError::InvalidGovernanceEmitter
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.governance_chain = chain;
+        config.governance_emitter = address;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction resizes a config account created before fields were
+    /// added to [Config] (e.g. [Config::pending_owner]). Fields missing from
+    /// the account are set to the same defaults as in
+    /// [`initialize`](initialize): no pending owner, nothing paused, no fees
+    /// (withdrawn to the owner), no rate limits, no allowlist and no
+    /// governance. The [Treasury] is created if it does not exist yet. This
+    /// instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `MigrateConfig` context
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let config = ctx.accounts.config.to_account_info();
+        require!(
+            config.owner == ctx.program_id && config.data_len() < Config::MAXIMUM_SIZE,
+            HelloWorldError::ConfigAlreadyMigrated
+        );
+
+        // Pay rent for the additional space.
+        let lamports = Rent::get()?
+            .minimum_balance(Config::MAXIMUM_SIZE)
+            .saturating_sub(config.lamports());
+        if lamports > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: config.clone(),
+                    },
+                ),
+                lamports,
             )?;
         }
+        config.realloc(Config::MAXIMUM_SIZE, true)?;
+
+        // Now that the account has been resized, it deserializes as Config.
+        let mut data = config.try_borrow_mut_data()?;
+        let mut migrated = Config::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(
+            migrated.owner,
+            ctx.accounts.owner.key(),
+            HelloWorldError::OwnerOnly
+        );
+        migrated.set_migrated_defaults();
+        migrated.try_serialize(&mut &mut data[..])?;
 
         // Done.
         Ok(())
     }
 
     /// This instruction registers a new foreign emitter (from another network)
-    /// and saves the emitter information in a ForeignEmitter account. This
-    /// instruction is owner-only, meaning that only the owner of the program
-    /// (defined in the [Config] account) can add and update emitters.
+    /// and saves the emitter information in a ForeignEmitter account. Each
+    /// chain can have multiple trusted emitters (e.g. an old and a new contract
+    /// during a migration), so registering an emitter does not replace the
+    /// others. Retire an emitter with [`deregister_emitter`](deregister_emitter).
+    /// Registering an emitter again does not re-enable it if it was disabled.
+    /// This instruction is owner-only, meaning that only the owner of the
+    /// program (defined in the [Config] account) can add and update emitters.
     ///
     /// # Arguments
     ///
@@ -194,11 +615,274 @@ pub mod hello_world {
             HelloWorldError::InvalidForeignEmitter,
         );
 
-        // Save the emitter info into the ForeignEmitter account.
+        // Save the emitter info into the ForeignEmitter account. A new
+        // emitter (whose chain is still zero) is enabled. Registering an
+        // existing emitter again keeps whether it is enabled, so a disabled
+        // emitter stays disabled until `set_emitter_enabled` is called.
         let emitter = &mut ctx.accounts.foreign_emitter;
+        if emitter.chain == 0 {
+            emitter.enabled = true;
+        }
         emitter.chain = chain;
         emitter.address = address;
 
+        emit_cpi!(EmitterRegistered { chain, address });
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction deregisters a foreign emitter by closing its
+    /// ForeignEmitter account and refunding its rent to the owner. Messages
+    /// from this emitter are rejected until it is registered again. Other
+    /// emitters registered for the same chain are unaffected. This instruction
+    /// is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `DeregisterEmitter` context
+    /// * `chain`   - Wormhole Chain ID
+    /// * `address` - Wormhole Emitter Address
+    pub fn deregister_emitter(
+        ctx: Context<DeregisterEmitter>,
+        chain: u16,
+        address: [u8; 32],
+    ) -> Result<()> {
+        emit_cpi!(EmitterDeregistered { chain, address });
+
+        // Anchor closes the ForeignEmitter account.
+        Ok(())
+    }
+
+    /// This instruction enables or disables a registered foreign emitter.
+    /// [`receive_message`](receive_message) rejects messages from disabled
+    /// emitters, which lets the owner pause an emitter without losing its
+    /// registration. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `UpdateEmitter` context
+    /// * `chain`   - Wormhole Chain ID
+    /// * `address` - Wormhole Emitter Address
+    /// * `enabled` - Whether to accept messages from this emitter
+    pub fn set_emitter_enabled(
+        ctx: Context<UpdateEmitter>,
+        _chain: u16,
+        _address: [u8; 32],
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.foreign_emitter.enabled = enabled;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction enables or disables ordered delivery for a registered
+    /// foreign emitter. In ordered mode,
+    /// [`receive_message`](receive_message) only accepts the message with the
+    /// emitter's next expected sequence. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`                    - `UpdateEmitter` context
+    /// * `chain`                  - Wormhole Chain ID
+    /// * `address`                - Wormhole Emitter Address
+    /// * `ordered`                - Whether to enforce in-order delivery
+    /// * `next_expected_sequence` - Sequence of the next message to accept
+    pub fn set_emitter_ordered(
+        ctx: Context<UpdateEmitter>,
+        _chain: u16,
+        _address: [u8; 32],
+        ordered: bool,
+        next_expected_sequence: u64,
+    ) -> Result<()> {
+        let emitter = &mut ctx.accounts.foreign_emitter;
+        emitter.ordered = ordered;
+        emitter.next_expected_sequence = next_expected_sequence;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction enables or disables acknowledgements for a registered
+    /// foreign emitter. When enabled, [`receive_message`](receive_message)
+    /// posts an `Ack` back to the emitter's chain for each `Hello` received.
+    /// This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`       - `UpdateEmitter` context
+    /// * `chain`     - Wormhole Chain ID
+    /// * `address`   - Wormhole Emitter Address
+    /// * `send_acks` - Whether to acknowledge messages from this emitter
+    pub fn set_emitter_acks(
+        ctx: Context<UpdateEmitter>,
+        _chain: u16,
+        _address: [u8; 32],
+        send_acks: bool,
+    ) -> Result<()> {
+        ctx.accounts.foreign_emitter.send_acks = send_acks;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction sets how old a message from a registered foreign
+    /// emitter can be when [`receive_message`](receive_message) receives it,
+    /// and optionally how far its timestamp can be in the future. In ordered
+    /// mode, a rejected message blocks later messages until the owner calls
+    /// [`skip_to_sequence`](skip_to_sequence). This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`              - `UpdateEmitter` context
+    /// * `chain`            - Wormhole Chain ID
+    /// * `address`          - Wormhole Emitter Address
+    /// * `max_message_age`  - Maximum age (seconds), or zero for no limit
+    /// * `max_future_drift` - Maximum seconds in the future, if limited
+    pub fn set_emitter_message_age(
+        ctx: Context<UpdateEmitter>,
+        _chain: u16,
+        _address: [u8; 32],
+        max_message_age: u32,
+        max_future_drift: Option<u32>,
+    ) -> Result<()> {
+        let emitter = &mut ctx.accounts.foreign_emitter;
+        emitter.max_message_age = max_message_age;
+        emitter.max_future_drift = max_future_drift;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction skips a gap in an ordered foreign emitter's sequences,
+    /// which is needed when a message is known to be lost. Messages with
+    /// skipped sequences can no longer be received. This instruction is
+    /// owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`                    - `UpdateEmitter` context
+    /// * `chain`                  - Wormhole Chain ID
+    /// * `address`                - Wormhole Emitter Address
+    /// * `next_expected_sequence` - Sequence of the next message to accept
+    pub fn skip_to_sequence(
+        ctx: Context<UpdateEmitter>,
+        _chain: u16,
+        _address: [u8; 32],
+        next_expected_sequence: u64,
+    ) -> Result<()> {
+        let emitter = &mut ctx.accounts.foreign_emitter;
+        require!(emitter.ordered, HelloWorldError::EmitterNotOrdered);
+        require!(
+            next_expected_sequence > emitter.next_expected_sequence,
+            HelloWorldError::InvalidSequenceSkip
+        );
+
+        emitter.next_expected_sequence = next_expected_sequence;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction resizes a foreign emitter account registered before
+    /// fields were added to [ForeignEmitter] (e.g. [ForeignEmitter::enabled]).
+    /// Fields missing from the account are set to their defaults: the emitter
+    /// is enabled, unordered, sends no acks and accepts messages of any age.
+    /// This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `MigrateEmitter` context
+    /// * `chain`   - Wormhole Chain ID
+    /// * `address` - Wormhole Emitter Address
+    pub fn migrate_emitter(
+        ctx: Context<MigrateEmitter>,
+        _chain: u16,
+        _address: [u8; 32],
+    ) -> Result<()> {
+        let foreign_emitter = ctx.accounts.foreign_emitter.to_account_info();
+        let legacy_size = foreign_emitter.data_len();
+        require!(
+            foreign_emitter.owner == ctx.program_id && legacy_size < ForeignEmitter::MAXIMUM_SIZE,
+            HelloWorldError::EmitterAlreadyMigrated
+        );
+
+        // Pay rent for the additional space.
+        let lamports = Rent::get()?
+            .minimum_balance(ForeignEmitter::MAXIMUM_SIZE)
+            .saturating_sub(foreign_emitter.lamports());
+        if lamports > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: foreign_emitter.clone(),
+                    },
+                ),
+                lamports,
+            )?;
+        }
+        foreign_emitter.realloc(ForeignEmitter::MAXIMUM_SIZE, true)?;
+
+        // The new bytes are zeroed, which is the default of every field except
+        // `enabled`. Emitters registered before `enabled` was added were
+        // trusted, so keep accepting their messages.
+        let mut data = foreign_emitter.try_borrow_mut_data()?;
+        let mut emitter = ForeignEmitter::try_deserialize(&mut &data[..])?;
+        if legacy_size <= ForeignEmitter::LEGACY_SIZE {
+            emitter.enabled = true;
+        }
+        emitter.try_serialize(&mut &mut data[..])?;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction moves a foreign emitter registered when there was one
+    /// emitter per chain (whose seeds do not include the address) to the
+    /// account [`register_emitter`](register_emitter) creates now. The legacy
+    /// account is closed and its rent refunded to the owner. Whether the
+    /// emitter is enabled is kept, and the other fields are set to their
+    /// defaults. This instruction is owner-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`     - `MigrateLegacyEmitter` context
+    /// * `chain`   - Wormhole Chain ID
+    /// * `address` - Wormhole Emitter Address saved in the legacy account
+    pub fn migrate_legacy_emitter(
+        ctx: Context<MigrateLegacyEmitter>,
+        chain: u16,
+        address: [u8; 32],
+    ) -> Result<()> {
+        let legacy_emitter = ctx.accounts.legacy_emitter.to_account_info();
+        let enabled = {
+            // Legacy accounts start with the same fields as ForeignEmitter,
+            // but may end before `enabled`.
+            let data = legacy_emitter.try_borrow_data()?;
+            require!(
+                legacy_emitter.owner == ctx.program_id
+                    && data.len() >= ForeignEmitter::LEGACY_SIZE
+                    && data.starts_with(ForeignEmitter::DISCRIMINATOR)
+                    && data[10..ForeignEmitter::LEGACY_SIZE] == address,
+                HelloWorldError::InvalidForeignEmitter
+            );
+            !matches!(data.get(ForeignEmitter::LEGACY_SIZE), Some(0))
+        };
+
+        let emitter = &mut ctx.accounts.foreign_emitter;
+        emitter.chain = chain;
+        emitter.address = address;
+        emitter.enabled = enabled;
+
+        // Close the legacy account and refund its rent to the owner.
+        ctx.accounts.owner.add_lamports(legacy_emitter.lamports())?;
+        legacy_emitter.sub_lamports(legacy_emitter.lamports())?;
+        legacy_emitter.assign(&System::id());
+        legacy_emitter.realloc(0, false)?;
+
         // Done.
         Ok(())
     }
@@ -213,10 +897,78 @@ pub mod hello_world {
     ///
     /// See [HelloWorldMessage] enum for serialization implementation.
     ///
+    /// The Wormhole message account is derived from the payer and a nonce
+    /// chosen by the payer, so many payers can send messages concurrently. A
+    /// nonce cannot be reused by the same payer. Returns the Wormhole sequence
+    /// of the message, which is also saved in the Wormhole message account.
+    ///
+    /// Messages are posted with the finality set in the [Config]. A sender can
+    /// request `Finalized` instead for high-value messages, which costs an
+    /// extra [Config::finalized_fee] paid to the [Treasury].
+    ///
+    /// Every message also costs the [Config::app_fee], which is paid to the
+    /// [Treasury].
+    ///
+    /// The sender is usually the payer. Another program can send messages
+    /// through this program by signing with its sender PDA (see
+    /// [SEED_PREFIX_SENDER]). If the allowlist is enabled, the sender must be
+    /// an allowed sender. Rate limits apply per sender.
+    ///
     /// # Arguments
     ///
-    /// * `message` - Arbitrary message to send out
-    pub fn send_message(ctx: Context<SendMessage>, message: Vec<u8>) -> Result<()> {
+    /// * `nonce`     - Unique (per payer) nonce used to derive the message account
+    /// * `message`   - Arbitrary message to send out
+    /// * `finalized` - Whether to post the message with `Finalized` finality
+    pub fn send_message(
+        ctx: Context<SendMessage>,
+        nonce: u64,
+        message: Vec<u8>,
+        finalized: bool,
+    ) -> Result<u64> {
+        let config = &ctx.accounts.config;
+        let now = ctx.accounts.clock.unix_timestamp;
+        // The global bucket is only provided if the global limit is enabled.
+        if let Some(global_rate_limit) = ctx.accounts.global_rate_limit.as_mut() {
+            global_rate_limit.consume(&config.global_rate_limit, now)?;
+        }
+        ctx.accounts
+            .sender_rate_limit
+            .consume(&config.sender_rate_limit, now)?;
+
+        let default_finality = wormhole::Finality::try_from(config.finality)
+            .map_err(|_| HelloWorldError::InvalidFinality)?;
+
+        // Pay the application fee to the treasury.
+        if config.app_fee > 0 {
+            solana_program::program::invoke(
+                &solana_program::system_instruction::transfer(
+                    &ctx.accounts.payer.key(),
+                    &ctx.accounts.treasury.key(),
+                    config.app_fee,
+                ),
+                &ctx.accounts.to_account_infos(),
+            )?;
+        }
+
+        // Requesting `Finalized` when it is already the default costs nothing.
+        // The fee is paid to the treasury, so that senders do not write-lock
+        // the owner.
+        let finality = if finalized && default_finality != wormhole::Finality::Finalized {
+            if config.finalized_fee > 0 {
+                solana_program::program::invoke(
+                    &solana_program::system_instruction::transfer(
+                        &ctx.accounts.payer.key(),
+                        &ctx.accounts.treasury.key(),
+                        config.finalized_fee,
+                    ),
+                    &ctx.accounts.to_account_infos(),
+                )?;
+            }
+            wormhole::Finality::Finalized
+        } else {
+            default_finality
+        };
+
         // If Wormhole requires a fee before posting a message, we need to
         // transfer lamports to the fee collector. Otherwise
         // `wormhole::post_message` will fail.
@@ -252,11 +1004,15 @@ pub mod hello_world {
         //
         // In our example, we use method #2.
         let wormhole_emitter = &ctx.accounts.wormhole_emitter;
-        let config = &ctx.accounts.config;
+
+        // The Wormhole program assigns the emitter's next sequence to this
+        // message.
+        let sequence = ctx.accounts.wormhole_sequence.next_value();
 
         // There is only one type of message that this example uses to
         // communicate with its foreign counterparts (payload ID == 1).
         let payload: Vec<u8> = HelloWorldMessage::Hello { message }.try_to_vec()?;
+        let payload_hash = solana_program::keccak::hash(&payload).to_bytes();
 
         wormhole::post_message(
             CpiContext::new_with_signer(
@@ -275,7 +1031,8 @@ pub mod hello_world {
                 &[
                     &[
                         SEED_PREFIX_SENT,
-                        &ctx.accounts.wormhole_sequence.next_value().to_le_bytes()[..],
+                        ctx.accounts.payer.key().as_ref(),
+                        &nonce.to_le_bytes()[..],
                         &[ctx.bumps.wormhole_message],
                     ],
                     &[wormhole::SEED_PREFIX_EMITTER, &[wormhole_emitter.bump]],
@@ -283,16 +1040,161 @@ pub mod hello_world {
             ),
             config.batch_id,
             payload,
-            config.finality.try_into().unwrap(),
+            finality,
         )?;
 
+        emit_cpi!(MessageSent {
+            sequence,
+            payload_hash,
+            payer: ctx.accounts.payer.key(),
+        });
+
         // Done.
-        Ok(())
+        Ok(sequence)
+    }
+
+    /// This instruction posts one chunk of a message too large for
+    /// [`send_message`](send_message) as [HelloWorldMessage::Chunk]. The
+    /// sender splits the message into chunks of [CHUNK_MAX_LENGTH] bytes (the
+    /// last chunk may be shorter) and sends each chunk with the same message
+    /// ID and Keccak256 hash of the whole message. Chunks can be sent in any
+    /// order. Each chunk is posted with the finality set in the [Config].
+    ///
+    /// Like [`send_message`](send_message), the Wormhole message account is
+    /// derived from the payer and a nonce, so each chunk needs its own nonce.
+    /// Returns the Wormhole sequence of the chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `nonce`      - Unique (per payer) nonce used to derive the message account
+    /// * `message_id` - ID shared by every chunk of the message
+    /// * `index`      - Index of this chunk
+    /// * `total`      - Number of chunks in the message
+    /// * `hash`       - Keccak256 hash of the whole message
+    /// * `data`       - Chunk of the message
+    pub fn send_message_chunk(
+        ctx: Context<SendMessage>,
+        nonce: u64,
+        message_id: u64,
+        index: u16,
+        total: u16,
+        hash: [u8; 32],
+        data: Vec<u8>,
+    ) -> Result<u64> {
+        require!(
+            Reassembly::is_valid_chunk(index, total, data.len()),
+            HelloWorldError::InvalidChunk
+        );
+
+        // Every chunk counts against the rate limits.
+        let config = &ctx.accounts.config;
+        let now = ctx.accounts.clock.unix_timestamp;
+        // The global bucket is only provided if the global limit is enabled.
+        if let Some(global_rate_limit) = ctx.accounts.global_rate_limit.as_mut() {
+            global_rate_limit.consume(&config.global_rate_limit, now)?;
+        }
+        ctx.accounts
+            .sender_rate_limit
+            .consume(&config.sender_rate_limit, now)?;
+
+        // Every chunk pays the application fee to the treasury.
+        if config.app_fee > 0 {
+            solana_program::program::invoke(
+                &solana_program::system_instruction::transfer(
+                    &ctx.accounts.payer.key(),
+                    &ctx.accounts.treasury.key(),
+                    config.app_fee,
+                ),
+                &ctx.accounts.to_account_infos(),
+            )?;
+        }
+
+        // If Wormhole requires a fee before posting a message, we need to
+        // transfer lamports to the fee collector. Otherwise
+        // `wormhole::post_message` will fail.
+        let fee = ctx.accounts.wormhole_bridge.fee();
+        if fee > 0 {
+            solana_program::program::invoke(
+                &solana_program::system_instruction::transfer(
+                    &ctx.accounts.payer.key(),
+                    &ctx.accounts.wormhole_fee_collector.key(),
+                    fee,
+                ),
+                &ctx.accounts.to_account_infos(),
+            )?;
+        }
+
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+        let sequence = ctx.accounts.wormhole_sequence.next_value();
+
+        let payload: Vec<u8> = HelloWorldMessage::Chunk {
+            message_id,
+            index,
+            total,
+            hash,
+            data,
+        }
+        .try_to_vec()?;
+        let payload_hash = solana_program::keccak::hash(&payload).to_bytes();
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[
+                    &[
+                        SEED_PREFIX_SENT,
+                        ctx.accounts.payer.key().as_ref(),
+                        &nonce.to_le_bytes()[..],
+                        &[ctx.bumps.wormhole_message],
+                    ],
+                    &[wormhole::SEED_PREFIX_EMITTER, &[wormhole_emitter.bump]],
+                ],
+            ),
+            config.batch_id,
+            payload,
+            config
+                .finality
+                .try_into()
+                .map_err(|_| HelloWorldError::InvalidFinality)?,
+        )?;
+
+        emit_cpi!(MessageSent {
+            sequence,
+            payload_hash,
+            payer: ctx.accounts.payer.key(),
+        });
+
+        // Done.
+        Ok(sequence)
     }
 
     /// This instruction reads a posted verified Wormhole message and verifies
-    /// that the payload is of type [HelloWorldMessage::Hello] (payload ID == 1). HelloWorldMessage
-    /// data is stored in a [Received] account.
+    /// that the payload is of type [HelloWorldMessage::Hello] (payload ID == 1)
+    /// or [HelloWorldMessage::Ack] (payload ID == 2). HelloWorldMessage data
+    /// is stored in a [Received] account.
+    ///
+    /// If the foreign emitter requested acknowledgements (see
+    /// [`set_emitter_acks`](set_emitter_acks)), an `Ack` carrying the
+    /// emitter chain, sequence and hash of the received `Hello` is posted back
+    /// through Wormhole. The payer pays the Wormhole fee for the `Ack`.
+    ///
+    /// An `Ack` acknowledges a message sent by this program, and is recorded
+    /// in an [Acknowledged] account. `Ack`s are never acknowledged.
+    ///
+    /// A `Chunk` (payload ID == 3) is written to a [Reassembly] account, which
+    /// holds the complete message once every chunk is received and the hash of
+    /// the reassembled message is verified.
     ///
     /// See [HelloWorldMessage] enum for deserialization implementation.
     ///
@@ -302,23 +1204,346 @@ pub mod hello_world {
     pub fn receive_message(ctx: Context<ReceiveMessage>, vaa_hash: [u8; 32]) -> Result<()> {
         let posted_message = &ctx.accounts.posted;
 
-        if let HelloWorldMessage::Hello { message } = posted_message.data() {
-            // HelloWorldMessage cannot be larger than the maximum size of the account.
-            require!(
-                message.len() <= MESSAGE_MAX_LENGTH,
-                HelloWorldError::InvalidMessage,
+        // Only an `Ack` is recorded in an Acknowledged account, and only a
+        // `Chunk` is written to a Reassembly account.
+        require!(
+            ctx.accounts.acknowledged.is_some()
+                == matches!(posted_message.data(), HelloWorldMessage::Ack { .. }),
+            HelloWorldError::InvalidAcknowledged
+        );
+        require!(
+            ctx.accounts.reassembly.is_some()
+                == matches!(posted_message.data(), HelloWorldMessage::Chunk { .. }),
+            HelloWorldError::InvalidChunk
+        );
+
+        let message = match posted_message.data() {
+            HelloWorldMessage::Hello { message } => {
+                // HelloWorldMessage cannot be larger than the maximum size of the account.
+                require!(
+                    message.len() <= MESSAGE_MAX_LENGTH,
+                    HelloWorldError::InvalidMessage,
+                );
+                message.clone()
+            }
+            HelloWorldMessage::Ack {
+                emitter_chain,
+                sequence,
+                vaa_hash: acked_vaa_hash,
+            } => {
+                // Only messages sent by this program can be acknowledged.
+                require_eq!(
+                    *emitter_chain,
+                    wormhole::CHAIN_ID_SOLANA,
+                    HelloWorldError::InvalidAck
+                );
+
+                let acknowledged = ctx
+                    .accounts
+                    .acknowledged
+                    .as_mut()
+                    .ok_or(HelloWorldError::InvalidAcknowledged)?;
+                acknowledged.sequence = *sequence;
+                acknowledged.vaa_hash = *acked_vaa_hash;
+                acknowledged.wormhole_message_hash = vaa_hash;
+
+                Vec::new()
+            }
+            HelloWorldMessage::Chunk {
+                message_id,
+                index,
+                total,
+                hash,
+                data,
+            } => {
+                require!(
+                    Reassembly::is_valid_chunk(*index, *total, data.len()),
+                    HelloWorldError::InvalidChunk
+                );
+
+                let reassembly = ctx
+                    .accounts
+                    .reassembly
+                    .as_mut()
+                    .ok_or(HelloWorldError::InvalidChunk)?;
+                if reassembly.is_new() {
+                    reassembly.payer = ctx.accounts.payer.key();
+                    reassembly.message_id = *message_id;
+                    reassembly.total_chunks = *total;
+                    reassembly.hash = *hash;
+                } else {
+                    // Every chunk must agree with the first one received.
+                    require!(
+                        !reassembly.is_finished()
+                            && reassembly.total_chunks == *total
+                            && reassembly.hash == *hash
+                            && !reassembly.has_chunk(*index),
+                        HelloWorldError::InvalidChunk
+                    );
+                }
+                reassembly.write_chunk(*index, data);
+                reassembly.last_updated = ctx.accounts.clock.unix_timestamp;
+
+                // The message is complete once every chunk is received and
+                // the reassembled message matches the hash. Otherwise the
+                // reassembly is marked as failed instead of reverting, so that
+                // its payer can close it without waiting for it to be
+                // abandoned.
+                if reassembly.has_all_chunks() {
+                    if solana_program::keccak::hash(&reassembly.message).to_bytes()
+                        == reassembly.hash
+                    {
+                        reassembly.complete = true;
+                    } else {
+                        reassembly.failed = true;
+                    }
+                }
+
+                Vec::new()
+            }
+            _ => return Err(HelloWorldError::InvalidMessage.into()),
+        };
+
+        // Reject messages that are too old (or too far in the future), based
+        // on the timestamp the guardians observed.
+        let emitter = &mut ctx.accounts.foreign_emitter;
+        require!(
+            emitter.is_timely(
+                posted_message.timestamp(),
+                ctx.accounts.clock.unix_timestamp
+            ),
+            HelloWorldError::UntimelyMessage
+        );
+
+        // In ordered mode, only the next expected sequence is accepted.
+        if emitter.ordered {
+            require_eq!(
+                posted_message.sequence(),
+                emitter.next_expected_sequence,
+                HelloWorldError::OutOfOrderMessage
             );
+            emitter.next_expected_sequence = emitter
+                .next_expected_sequence
+                .checked_add(1)
+                .ok_or(HelloWorldError::SequenceOverflow)?;
+        }
 
-            // Save batch ID, keccak256 hash and message payload.
-            let received = &mut ctx.accounts.received;
-            received.batch_id = posted_message.batch_id();
-            received.wormhole_message_hash = vaa_hash;
-            received.message = message.clone();
+        let send_ack =
+            emitter.send_acks && matches!(posted_message.data(), HelloWorldMessage::Hello { .. });
 
-            // Done
-            Ok(())
-        } else {
-            Err(HelloWorldError::InvalidMessage.into())
+        // Save batch ID, keccak256 hash and message payload.
+        let received = &mut ctx.accounts.received;
+        received.batch_id = posted_message.batch_id();
+        received.wormhole_message_hash = vaa_hash;
+        received.message = message;
+        received.payer = ctx.accounts.payer.key();
+
+        emit_cpi!(MessageReceived {
+            emitter_chain: posted_message.emitter_chain(),
+            emitter_address: *posted_message.emitter_address(),
+            sequence: posted_message.sequence(),
+            vaa_hash,
+        });
+
+        if send_ack {
+            // If Wormhole requires a fee before posting a message, we need to
+            // transfer lamports to the fee collector. Otherwise
+            // `wormhole::post_message` will fail.
+            let fee = ctx.accounts.wormhole_bridge.fee();
+            if fee > 0 {
+                solana_program::program::invoke(
+                    &solana_program::system_instruction::transfer(
+                        &ctx.accounts.payer.key(),
+                        &ctx.accounts.wormhole_fee_collector.key(),
+                        fee,
+                    ),
+                    &ctx.accounts.to_account_infos(),
+                )?;
+            }
+
+            let config = &ctx.accounts.config;
+            let posted_message = &ctx.accounts.posted;
+            let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+            let sequence = ctx.accounts.wormhole_sequence.next_value();
+
+            let payload: Vec<u8> = HelloWorldMessage::Ack {
+                emitter_chain: posted_message.emitter_chain(),
+                sequence: posted_message.sequence(),
+                vaa_hash,
+            }
+            .try_to_vec()?;
+            let payload_hash = solana_program::keccak::hash(&payload).to_bytes();
+
+            wormhole::post_message(
+                CpiContext::new_with_signer(
+                    ctx.accounts.wormhole_program.to_account_info(),
+                    wormhole::PostMessage {
+                        config: ctx.accounts.wormhole_bridge.to_account_info(),
+                        message: ctx.accounts.wormhole_message.to_account_info(),
+                        emitter: wormhole_emitter.to_account_info(),
+                        sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                        payer: ctx.accounts.payer.to_account_info(),
+                        fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                        clock: ctx.accounts.clock.to_account_info(),
+                        rent: ctx.accounts.rent.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    },
+                    &[
+                        &[
+                            SEED_PREFIX_ACK,
+                            &posted_message.emitter_chain().to_le_bytes()[..],
+                            &posted_message.emitter_address()[..],
+                            &posted_message.sequence().to_le_bytes()[..],
+                            &[ctx.bumps.wormhole_message],
+                        ],
+                        &[wormhole::SEED_PREFIX_EMITTER, &[wormhole_emitter.bump]],
+                    ],
+                ),
+                config.batch_id,
+                payload,
+                config
+                    .finality
+                    .try_into()
+                    .map_err(|_| HelloWorldError::InvalidFinality)?,
+            )?;
+
+            emit_cpi!(MessageSent {
+                sequence,
+                payload_hash,
+                payer: ctx.accounts.payer.key(),
+            });
+        }
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction shrinks a [Received] account to a tombstone and
+    /// refunds the excess rent to the payer who created it. The tombstone
+    /// keeps the Wormhole message hash and still prevents the message from
+    /// being replayed. Only the payer can call this instruction, which should
+    /// be done after the message is processed. Legacy [Received] accounts do
+    /// not record their payer, so they are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CloseReceived` context
+    pub fn close_received(ctx: Context<CloseReceived>) -> Result<()> {
+        // Anchor resizes the account and refunds the excess rent. The message
+        // is cleared so that the account data fits in the tombstone.
+        ctx.accounts.received.message.clear();
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction closes a [Reassembly] account and refunds its rent to
+    /// the payer who created it. The payer can close a complete reassembly
+    /// once its message is processed, or a failed one whose message did not
+    /// match its hash. Anyone can close an abandoned reassembly, which is
+    /// incomplete and has not received a chunk within [REASSEMBLY_TIMEOUT].
+    /// The account is shrunk to a tombstone, so chunks of a closed reassembly
+    /// cannot be received again.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CloseReassembly` context
+    pub fn close_reassembly(ctx: Context<CloseReassembly>) -> Result<()> {
+        let reassembly = &mut ctx.accounts.reassembly;
+        require!(
+            (reassembly.is_finished() && ctx.accounts.closer.key() == reassembly.payer)
+                || reassembly.is_abandoned(Clock::get()?.unix_timestamp),
+            HelloWorldError::ReassemblyInProgress
+        );
+
+        // Anchor resizes the account and refunds the excess rent. The message
+        // is cleared so that the account data fits in the tombstone.
+        reassembly.message.clear();
+        reassembly.closed = true;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction reads a posted verified governance Wormhole message
+    /// emitted by the governance emitter set in the [Config] and executes its
+    /// [GovernanceAction]. This lets a governance contract on another chain
+    /// (e.g. a DAO) administer this program without the owner's key. Anyone
+    /// can call this instruction.
+    ///
+    /// The governance message must target Solana's chain ID. Each governance
+    /// VAA can only be executed once, which is recorded in an [Executed]
+    /// account.
+    ///
+    /// See [GovernanceMessage] for deserialization implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `vaa_hash` - Keccak256 hash of verified Wormhole message
+    pub fn execute_governance(ctx: Context<ExecuteGovernance>, vaa_hash: [u8; 32]) -> Result<()> {
+        let posted = &ctx.accounts.posted;
+        let governance = posted.data();
+        require!(
+            governance.target_chain == wormhole::CHAIN_ID_SOLANA,
+            HelloWorldError::InvalidTargetChain
+        );
+
+        // The foreign emitter account is only provided to register an
+        // emitter.
+        require!(
+            ctx.accounts.foreign_emitter.is_some() == governance.emitter_to_register().is_some(),
+            HelloWorldError::InvalidForeignEmitter
+        );
+
+        let config = &mut ctx.accounts.config;
+        match governance.action {
+            GovernanceAction::RegisterEmitter { chain, address } => {
+                // Same checks as `register_emitter`.
+                require!(
+                    chain > 0
+                        && chain != wormhole::CHAIN_ID_SOLANA
+                        && !address.iter().all(|&x| x == 0),
+                    HelloWorldError::InvalidForeignEmitter,
+                );
+
+                let emitter = ctx.accounts.foreign_emitter.as_mut().unwrap();
+                emitter.chain = chain;
+                emitter.address = address;
+                emitter.enabled = true;
+
+                emit_cpi!(EmitterRegistered { chain, address });
+            }
+            GovernanceAction::SetFee {
+                app_fee,
+                fee_recipient,
+            } => {
+                require!(
+                    fee_recipient != Pubkey::default(),
+                    HelloWorldError::InvalidFeeRecipient
+                );
+
+                config.app_fee = app_fee;
+                config.fee_recipient = fee_recipient;
+            }
+            GovernanceAction::Pause { outbound, inbound } => {
+                config.pause_outbound = outbound;
+                config.pause_inbound = inbound;
+            }
+            GovernanceAction::TransferOwnership { new_owner } => {
+                require!(
+                    new_owner != Pubkey::default(),
+                    HelloWorldError::InvalidNewOwner
+                );
+
+                config.owner = new_owner;
+                config.pending_owner = None;
+            }
         }
+
+        // Prevent this governance VAA from being executed again.
+        ctx.accounts.executed.wormhole_message_hash = vaa_hash;
+
+        // Done.
+        Ok(())
     }
 }
@@ -4,8 +4,11 @@ use wormhole_io::Readable;
 
 const PAYLOAD_ID_ALIVE: u8 = 0;
 const PAYLOAD_ID_HELLO: u8 = 1;
+const PAYLOAD_ID_ACK: u8 = 2;
+const PAYLOAD_ID_CHUNK: u8 = 3;
 
 pub const HELLO_MESSAGE_MAX_LENGTH: usize = 512;
+pub const CHUNK_MAX_LENGTH: usize = 512;
 
 #[derive(Clone)]
 /// Expected message types for this program. Only valid payloads are:
@@ -13,11 +16,52 @@ pub const HELLO_MESSAGE_MAX_LENGTH: usize = 512;
 ///  is called).
 /// * `Hello`: Payload ID == 1. Emitted when
 /// [`send_message`](crate::send_message) is called).
+/// * `Ack`: Payload ID == 2. Emitted when
+/// [`receive_message`](crate::receive_message) receives a `Hello` from an
+/// emitter that requested acknowledgements.
+/// * `Chunk`: Payload ID == 3. Emitted when
+/// [`send_message_chunk`](crate::send_message_chunk) is called. A message
+/// too large for `Hello` is split into chunks, which share a message ID and
+/// the Keccak256 hash of the whole message.
 ///
 /// Payload IDs are encoded as u8.
 pub enum HelloWorldMessage {
-    Alive { program_id: Pubkey },
-    Hello { message: Vec<u8> },
+    Alive {
+        program_id: Pubkey,
+    },
+    Hello {
+        message: Vec<u8>,
+    },
+    Ack {
+        emitter_chain: u16,
+        sequence: u64,
+        vaa_hash: [u8; 32],
+    },
+    Chunk {
+        message_id: u64,
+        index: u16,
+        total: u16,
+        hash: [u8; 32],
+        data: Vec<u8>,
+    },
+}
+
+impl HelloWorldMessage {
+    /// Sequence of the acknowledged message if this is an `Ack`.
+    pub fn ack_sequence(&self) -> Option<u64> {
+        match self {
+            HelloWorldMessage::Ack { sequence, .. } => Some(*sequence),
+            _ => None,
+        }
+    }
+
+    /// Message ID of the chunked message if this is a `Chunk`.
+    pub fn chunk_message_id(&self) -> Option<u64> {
+        match self {
+            HelloWorldMessage::Chunk { message_id, .. } => Some(*message_id),
+            _ => None,
+        }
+    }
 }
 
 impl AnchorSerialize for HelloWorldMessage {
@@ -42,6 +86,41 @@ impl AnchorSerialize for HelloWorldMessage {
                     Ok(())
                 }
             }
+            HelloWorldMessage::Ack {
+                emitter_chain,
+                sequence,
+                vaa_hash,
+            } => {
+                PAYLOAD_ID_ACK.serialize(writer)?;
+                emitter_chain.to_be_bytes().serialize(writer)?;
+                sequence.to_be_bytes().serialize(writer)?;
+                vaa_hash.serialize(writer)
+            }
+            HelloWorldMessage::Chunk {
+                message_id,
+                index,
+                total,
+                hash,
+                data,
+            } => {
+                if data.len() > CHUNK_MAX_LENGTH {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("chunk exceeds {CHUNK_MAX_LENGTH} bytes"),
+                    ))
+                } else {
+                    PAYLOAD_ID_CHUNK.serialize(writer)?;
+                    message_id.to_be_bytes().serialize(writer)?;
+                    index.to_be_bytes().serialize(writer)?;
+                    total.to_be_bytes().serialize(writer)?;
+                    hash.serialize(writer)?;
+                    (data.len() as u16).to_be_bytes().serialize(writer)?;
+                    for item in data {
+                        item.serialize(writer)?;
+                    }
+                    Ok(())
+                }
+            }
         }
     }
 }
@@ -65,6 +144,34 @@ impl AnchorDeserialize for HelloWorldMessage {
                     Ok(HelloWorldMessage::Hello { message: buf })
                 }
             }
+            PAYLOAD_ID_ACK => Ok(HelloWorldMessage::Ack {
+                emitter_chain: u16::read(reader)?,
+                sequence: u64::read(reader)?,
+                vaa_hash: <[u8; 32]>::read(reader)?,
+            }),
+            PAYLOAD_ID_CHUNK => {
+                let message_id = u64::read(reader)?;
+                let index = u16::read(reader)?;
+                let total = u16::read(reader)?;
+                let hash = <[u8; 32]>::read(reader)?;
+                let length = u16::read(reader)? as usize;
+                if length > CHUNK_MAX_LENGTH {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("chunk exceeds {CHUNK_MAX_LENGTH} bytes"),
+                    ))
+                } else {
+                    let mut data = vec![0; length];
+                    reader.read_exact(&mut data)?;
+                    Ok(HelloWorldMessage::Chunk {
+                        message_id,
+                        index,
+                        total,
+                        hash,
+                        data,
+                    })
+                }
+            }
             _ => Err(io::Error::new(
                 io::ErrorKind::InvalidInput,
                 "invalid payload ID",
@@ -203,4 +310,125 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_message_ack() -> Result<()> {
+        let msg = HelloWorldMessage::Ack {
+            emitter_chain: 2,
+            sequence: 69,
+            vaa_hash: [42; 32],
+        };
+
+        // Serialize message above.
+        let mut encoded = Vec::new();
+        msg.serialize(&mut encoded)?;
+
+        assert_eq!(
+            encoded.len(),
+            size_of::<u8>() + size_of::<u16>() + size_of::<u64>() + size_of::<[u8; 32]>()
+        );
+
+        // Verify Payload ID.
+        assert_eq!(encoded[0], PAYLOAD_ID_ACK);
+
+        // Verify emitter chain and sequence (big endian).
+        assert_eq!(encoded[1..3], 2u16.to_be_bytes());
+        assert_eq!(encoded[3..11], 69u64.to_be_bytes());
+
+        // Verify VAA hash.
+        assert_eq!(encoded[11..], [42; 32]);
+
+        // Now deserialize the encoded message.
+        let decoded = HelloWorldMessage::deserialize(&mut encoded.as_slice())?;
+        assert_eq!(decoded.ack_sequence(), Some(69));
+        match decoded {
+            HelloWorldMessage::Ack {
+                emitter_chain,
+                sequence,
+                vaa_hash,
+            } => {
+                assert_eq!(emitter_chain, 2);
+                assert_eq!(sequence, 69);
+                assert_eq!(vaa_hash, [42; 32]);
+            }
+            _ => assert!(false, "incorrect deserialization"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_chunk() -> Result<()> {
+        let raw_data = b"All your base are belong to us".to_vec();
+        let msg = HelloWorldMessage::Chunk {
+            message_id: 420,
+            index: 1,
+            total: 3,
+            hash: [69; 32],
+            data: raw_data.clone(),
+        };
+
+        // Serialize message above.
+        let mut encoded = Vec::new();
+        msg.serialize(&mut encoded)?;
+
+        assert_eq!(
+            encoded.len(),
+            size_of::<u8>()
+                + size_of::<u64>()
+                + size_of::<u16>()
+                + size_of::<u16>()
+                + size_of::<[u8; 32]>()
+                + size_of::<u16>()
+                + raw_data.len()
+        );
+
+        // Verify Payload ID.
+        assert_eq!(encoded[0], PAYLOAD_ID_CHUNK);
+
+        // Verify message ID, index and total (big endian).
+        assert_eq!(encoded[1..9], 420u64.to_be_bytes());
+        assert_eq!(encoded[9..11], 1u16.to_be_bytes());
+        assert_eq!(encoded[11..13], 3u16.to_be_bytes());
+
+        // Verify hash, data length and data.
+        assert_eq!(encoded[13..45], [69; 32]);
+        assert_eq!(encoded[45..47], (raw_data.len() as u16).to_be_bytes());
+        assert_eq!(encoded[47..], raw_data);
+
+        // Now deserialize the encoded message.
+        let decoded = HelloWorldMessage::deserialize(&mut encoded.as_slice())?;
+        assert_eq!(decoded.chunk_message_id(), Some(420));
+        match decoded {
+            HelloWorldMessage::Chunk {
+                message_id,
+                index,
+                total,
+                hash,
+                data,
+            } => {
+                assert_eq!(message_id, 420);
+                assert_eq!(index, 1);
+                assert_eq!(total, 3);
+                assert_eq!(hash, [69; 32]);
+                assert_eq!(data, raw_data);
+            }
+            _ => assert!(false, "incorrect deserialization"),
+        }
+
+        // Chunks larger than the maximum cannot be serialized.
+        let msg = HelloWorldMessage::Chunk {
+            message_id: 420,
+            index: 0,
+            total: 1,
+            hash: [69; 32],
+            data: vec![33; CHUNK_MAX_LENGTH + 1],
+        };
+        match msg.serialize(&mut Vec::new()) {
+            Err(e) => assert_eq!(e.kind(), io::ErrorKind::InvalidInput),
+            _ => assert!(false, "not supposed to serialize"),
+        };
+
+        Ok(())
+    }
 }
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+/// Acknowledged account. There is one account per message sent by this
+/// program and acknowledged by a foreign emitter, which confirms that the
+/// message was delivered.
+pub struct Acknowledged {
+    /// Sequence of the acknowledged message sent by this program.
+    pub sequence: u64,
+    /// Keccak256 hash of the acknowledged Wormhole message, as computed by the
+    /// foreign emitter.
+    pub vaa_hash: [u8; 32],
+    /// Keccak256 hash of the verified Wormhole message carrying the `Ack`.
+    pub wormhole_message_hash: [u8; 32],
+}
+
+impl Acknowledged {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 8 // sequence
+        + 32 // vaa_hash
+        + 32 // wormhole_message_hash
+    ;
+    /// AKA `b"acknowledged"`.
+    pub const SEED_PREFIX: &'static [u8; 12] = b"acknowledged";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_acknowledged() -> Result<()> {
+        assert_eq!(
+            Acknowledged::MAXIMUM_SIZE,
+            size_of::<u64>() + size_of::<u64>() + size_of::<[u8; 32]>() + size_of::<[u8; 32]>()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+/// Allowed sender account. There is one account per sender that can call
+/// [`send_message`](crate::hello_world::send_message) while the allowlist is
+/// enabled.
+pub struct AllowedSender {
+    /// Sender's pubkey, which must sign for sending messages.
+    pub sender: Pubkey,
+    /// If the sender is a PDA that a program signs with to send messages
+    /// through this program via CPI, that program's ID.
+    pub program: Option<Pubkey>,
+}
+
+impl AllowedSender {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // sender
+        + 1 + 32 // program
+    ;
+    /// AKA `b"allowed_sender"`.
+    pub const SEED_PREFIX: &'static [u8; 14] = b"allowed_sender";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_allowed_sender() -> Result<()> {
+        assert_eq!(
+            AllowedSender::MAXIMUM_SIZE,
+            size_of::<u64>() + size_of::<Pubkey>() + size_of::<u8>() + size_of::<Pubkey>()
+        );
+
+        Ok(())
+    }
+}
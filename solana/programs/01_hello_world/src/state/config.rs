@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use super::RateLimitParams;
+
 #[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 /// Wormhole program related addresses.
 pub struct WormholeAddresses {
@@ -33,6 +35,36 @@ pub struct Config {
     /// AKA consistency level. u8 representation of Solana's
     /// [Finality](wormhole_anchor_sdk::wormhole::Finality).
     pub finality: u8,
+    /// Owner proposed by the current owner. This owner must accept ownership
+    /// before it takes effect.
+    pub pending_owner: Option<Pubkey>,
+    /// Whether [`send_message`](crate::hello_world::send_message) is paused.
+    pub pause_outbound: bool,
+    /// Whether [`receive_message`](crate::hello_world::receive_message) is
+    /// paused.
+    pub pause_inbound: bool,
+    /// Fee (lamports) paid to the [`Treasury`](crate::state::Treasury) by
+    /// senders who request `Finalized` finality for their message.
+    pub finalized_fee: u64,
+    /// Token bucket shared by every sender.
+    pub global_rate_limit: RateLimitParams,
+    /// Token bucket of each sender.
+    pub sender_rate_limit: RateLimitParams,
+    /// Fee (lamports) paid to the treasury for every message sent.
+    pub app_fee: u64,
+    /// Recipient of the fees withdrawn from the treasury.
+    pub fee_recipient: Pubkey,
+    /// Whether only allowed senders (see
+    /// [`add_allowed_sender`](crate::hello_world::add_allowed_sender)) can send
+    /// messages.
+    pub allowlist_enabled: bool,
+    /// Wormhole chain ID of the governance emitter, whose governance VAAs
+    /// can be executed with
+    /// [`execute_governance`](crate::hello_world::execute_governance). Zero
+    /// if governance is disabled.
+    pub governance_chain: u16,
+    /// Address of the governance emitter.
+    pub governance_emitter: [u8; 32],
 }
 
 impl Config {
@@ -41,10 +73,53 @@ impl Config {
         + WormholeAddresses::LEN
         + 4 // batch_id
         + 1 // finality
-        
+        + 1 + 32 // pending_owner
+        + 1 // pause_outbound
+        + 1 // pause_inbound
+        + 8 // finalized_fee
+        + RateLimitParams::LEN // global_rate_limit
+        + RateLimitParams::LEN // sender_rate_limit
+        + 8 // app_fee
+        + 32 // fee_recipient
+        + 1 // allowlist_enabled
+        + 2 // governance_chain
+        + 32 // governance_emitter
+    ;
+    /// Size of config accounts created before `pending_owner` was added.
+    /// Config accounts smaller than [`Config::MAXIMUM_SIZE`] must be migrated
+    /// with [`migrate_config`](crate::hello_world::migrate_config).
+    pub const LEGACY_SIZE: usize = 8 // discriminator
+        + 32 // owner
+        + WormholeAddresses::LEN
+        + 4 // batch_id
+        + 1 // finality
     ;
     /// AKA `b"config"`.
     pub const SEED_PREFIX: &'static [u8; 6] = b"config";
+
+    /// Set defaults for fields appended to a legacy config account by
+    /// [`migrate_config`](crate::hello_world::migrate_config). The appended
+    /// bytes are zeroed, which is already the default of every field except
+    /// the fee recipient. A zero fee recipient cannot be set with
+    /// [`update_fee_settings`](crate::hello_world::update_fee_settings), so it
+    /// only appears in migrated accounts.
+    pub fn set_migrated_defaults(&mut self) {
+        if self.fee_recipient == Pubkey::default() {
+            self.fee_recipient = self.owner;
+        }
+    }
+
+    /// Whether governance VAAs can be executed.
+    pub fn is_governance_enabled(&self) -> bool {
+        self.governance_chain > 0
+    }
+
+    /// Whether the emitter is the governance emitter.
+    pub fn is_governance_emitter(&self, chain: u16, address: &[u8; 32]) -> bool {
+        self.is_governance_enabled()
+            && chain == self.governance_chain
+            && *address == self.governance_emitter
+    }
 }
 
 #[cfg(test)]
@@ -62,8 +137,76 @@ mod test {
             + size_of::<WormholeAddresses>()
             + size_of::<u32>()
             + size_of::<u8>()
+            + size_of::<u8>() + size_of::<Pubkey>()
+            + size_of::<bool>()
+            + size_of::<bool>()
+            + size_of::<u64>()
+            + size_of::<RateLimitParams>()
+            + size_of::<RateLimitParams>()
+            + size_of::<u64>()
+            + size_of::<Pubkey>()
+            + size_of::<bool>()
+            + size_of::<u16>()
+            + size_of::<[u8; 32]>()
         );
 
+        let config = Config {
+            owner: Pubkey::new_unique(),
+            batch_id: 69,
+            finality: 1,
+            pending_owner: Some(Pubkey::new_unique()),
+            pause_outbound: true,
+            pause_inbound: true,
+            finalized_fee: 420,
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        config.try_serialize(&mut data)?;
+        assert_eq!(data.len(), Config::MAXIMUM_SIZE);
+
+        // Legacy config accounts are migrated by appending zeros, which
+        // deserializes as no pending owner and nothing paused.
+        data.truncate(Config::LEGACY_SIZE);
+        data.resize(Config::MAXIMUM_SIZE, 0);
+        let migrated = Config::try_deserialize(&mut data.as_slice())?;
+        assert_eq!(migrated.owner, config.owner);
+        assert_eq!(migrated.batch_id, config.batch_id);
+        assert_eq!(migrated.finality, config.finality);
+        assert!(migrated.pending_owner.is_none());
+        assert!(!migrated.pause_outbound && !migrated.pause_inbound);
+        assert_eq!(migrated.finalized_fee, 0);
+        assert!(!migrated.global_rate_limit.is_enabled());
+        assert!(!migrated.sender_rate_limit.is_enabled());
+        assert_eq!(migrated.app_fee, 0);
+        assert_eq!(migrated.fee_recipient, Pubkey::default());
+        assert!(!migrated.allowlist_enabled);
+        assert!(!migrated.is_governance_enabled());
+
+        // Fees are withdrawn to the owner by default, as in `initialize`.
+        let mut migrated = migrated;
+        migrated.set_migrated_defaults();
+        assert_eq!(migrated.fee_recipient, config.owner);
+
+        // A fee recipient that was set before migrating is kept.
+        let fee_recipient = Pubkey::new_unique();
+        let mut migrated = Config {
+            fee_recipient,
+            ..migrated
+        };
+        migrated.set_migrated_defaults();
+        assert_eq!(migrated.fee_recipient, fee_recipient);
+
+        // Only the governance emitter set in the config is trusted.
+        let config = Config {
+            governance_chain: 2,
+            governance_emitter: [69; 32],
+            ..Default::default()
+        };
+        assert!(config.is_governance_emitter(2, &[69; 32]));
+        assert!(!config.is_governance_emitter(2, &[42; 32]));
+        assert!(!config.is_governance_emitter(3, &[69; 32]));
+        assert!(!Config::default().is_governance_emitter(0, &[0; 32]));
+
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+/// Executed account. There is one account per governance VAA executed by
+/// [`execute_governance`](crate::hello_world::execute_governance). This
+/// account is never closed, which prevents the governance VAA from being
+/// replayed.
+pub struct Executed {
+    /// Keccak256 hash of the verified governance Wormhole message.
+    pub wormhole_message_hash: [u8; 32],
+}
+
+impl Executed {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // wormhole_message_hash
+    ;
+    /// AKA `b"executed"`.
+    pub const SEED_PREFIX: &'static [u8; 8] = b"executed";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_executed() -> Result<()> {
+        assert_eq!(
+            Executed::MAXIMUM_SIZE,
+            size_of::<u64>() + size_of::<[u8; 32]>()
+        );
+
+        Ok(())
+    }
+}
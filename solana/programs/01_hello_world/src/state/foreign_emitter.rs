@@ -2,18 +2,48 @@ use anchor_lang::prelude::*;
 
 #[account]
 #[derive(Default)]
-/// Foreign emitter account data.
+/// Foreign emitter account data. There is one account per trusted emitter,
+/// so a chain can have more than one.
 pub struct ForeignEmitter {
     /// Emitter chain. Cannot equal `1` (Solana's Chain ID).
     pub chain: u16,
     /// Emitter address. Cannot be zero address.
     pub address: [u8; 32],
+    /// Whether messages from this emitter are accepted. The owner can disable
+    /// an emitter without deregistering it.
+    pub enabled: bool,
+    /// Whether messages from this emitter must be received in order.
+    pub ordered: bool,
+    /// In ordered mode, the sequence of the next message to be received.
+    pub next_expected_sequence: u64,
+    /// Whether to post an `Ack` back to this emitter for each `Hello` received.
+    pub send_acks: bool,
+    /// Maximum age (seconds) of a message's timestamp when it is received.
+    /// Zero means no limit.
+    pub max_message_age: u32,
+    /// Maximum seconds a message's timestamp can be ahead of the clock when it
+    /// is received. `None` means no limit.
+    pub max_future_drift: Option<u32>,
 }
 
 impl ForeignEmitter {
     pub const MAXIMUM_SIZE: usize = 8 // discriminator
         + 2 // chain
         + 32 // address
+        + 1 // enabled
+        + 1 // ordered
+        + 8 // next_expected_sequence
+        + 1 // send_acks
+        + 4 // max_message_age
+        + 1 + 4 // max_future_drift
+    ;
+    /// Size of foreign emitter accounts created before `enabled` was added.
+    /// Foreign emitter accounts smaller than [`ForeignEmitter::MAXIMUM_SIZE`]
+    /// must be migrated with
+    /// [`migrate_emitter`](crate::hello_world::migrate_emitter).
+    pub const LEGACY_SIZE: usize = 8 // discriminator
+        + 2 // chain
+        + 32 // address
     ;
     /// AKA `b"foreign_emitter"`.
     pub const SEED_PREFIX: &'static [u8; 15] = b"foreign_emitter";
@@ -23,6 +53,18 @@ impl ForeignEmitter {
     pub fn verify(&self, address: &[u8; 32]) -> bool {
         *address == self.address
     }
+
+    /// Whether a message with this timestamp is neither too old nor too far
+    /// in the future at `now`.
+    pub fn is_timely(&self, timestamp: u32, now: i64) -> bool {
+        let age = now - i64::from(timestamp);
+        let too_old = self.max_message_age > 0 && age > i64::from(self.max_message_age);
+        let too_new = match self.max_future_drift {
+            Some(drift) => -age > i64::from(drift),
+            None => false,
+        };
+        !too_old && !too_new
+    }
 }
 
 #[cfg(test)]
@@ -34,7 +76,16 @@ mod test {
     fn test_foreign_emitter() -> Result<()> {
         assert_eq!(
             ForeignEmitter::MAXIMUM_SIZE,
-            size_of::<u64>() + size_of::<u16>() + size_of::<[u8; 32]>()
+            size_of::<u64>()
+                + size_of::<u16>()
+                + size_of::<[u8; 32]>()
+                + size_of::<bool>()
+                + size_of::<bool>()
+                + size_of::<u64>()
+                + size_of::<bool>()
+                + size_of::<u32>()
+                + size_of::<u8>()
+                + size_of::<u32>()
         );
 
         let chain = 2u16;
@@ -42,12 +93,50 @@ mod test {
             4u8, 20u8, 6u8, 9u8, 4u8, 20u8, 6u8, 9u8, 4u8, 20u8, 6u8, 9u8, 4u8, 20u8, 6u8, 9u8,
             4u8, 20u8, 6u8, 9u8, 4u8, 20u8, 6u8, 9u8, 4u8, 20u8, 6u8, 9u8, 4u8, 20u8, 6u8, 9u8,
         ];
-        let foreign_emitter = ForeignEmitter { chain, address };
+        let foreign_emitter = ForeignEmitter {
+            chain,
+            address,
+            enabled: true,
+            ..Default::default()
+        };
         assert!(
             foreign_emitter.verify(&address),
             "foreign_emitter.verify(address) failed"
         );
 
+        // Any timestamp is accepted without limits.
+        let now = 1_000_000;
+        assert!(foreign_emitter.is_timely(0, now));
+        assert!(foreign_emitter.is_timely(u32::MAX, now));
+
+        let foreign_emitter = ForeignEmitter {
+            max_message_age: 60,
+            max_future_drift: Some(10),
+            ..foreign_emitter
+        };
+        assert!(foreign_emitter.is_timely(1_000_000 - 60, now));
+        assert!(!foreign_emitter.is_timely(1_000_000 - 61, now));
+        assert!(foreign_emitter.is_timely(1_000_000 + 10, now));
+        assert!(!foreign_emitter.is_timely(1_000_000 + 11, now));
+
+        // Legacy foreign emitter accounts are migrated by appending zeros,
+        // which deserializes as an unordered emitter without limits. Only
+        // `enabled` has to be set explicitly.
+        let mut data = Vec::new();
+        foreign_emitter.try_serialize(&mut data)?;
+        assert_eq!(data.len(), ForeignEmitter::MAXIMUM_SIZE);
+        data.truncate(ForeignEmitter::LEGACY_SIZE);
+        data.resize(ForeignEmitter::MAXIMUM_SIZE, 0);
+        let migrated = ForeignEmitter::try_deserialize(&mut data.as_slice())?;
+        assert_eq!(migrated.chain, chain);
+        assert!(migrated.verify(&address));
+        assert!(!migrated.enabled);
+        assert!(!migrated.ordered);
+        assert_eq!(migrated.next_expected_sequence, 0);
+        assert!(!migrated.send_acks);
+        assert!(migrated.is_timely(0, now));
+        assert!(migrated.is_timely(u32::MAX, now));
+
         Ok(())
     }
 }
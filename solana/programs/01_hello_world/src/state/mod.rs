@@ -1,9 +1,21 @@
+pub use acknowledged::*;
+pub use allowed_sender::*;
 pub use config::*;
+pub use executed::*;
 pub use foreign_emitter::*;
+pub use rate_limit::*;
+pub use reassembly::*;
 pub use received::*;
+pub use treasury::*;
 pub use wormhole_emitter::*;
 
+pub mod acknowledged;
+pub mod allowed_sender;
 pub mod config;
+pub mod executed;
 pub mod foreign_emitter;
+pub mod rate_limit;
+pub mod reassembly;
 pub mod received;
+pub mod treasury;
 pub mod wormhole_emitter;
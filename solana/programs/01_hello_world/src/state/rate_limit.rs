@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use crate::error::HelloWorldError;
+
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+/// Token bucket parameters set by the owner. A zero capacity means no limit.
+pub struct RateLimitParams {
+    /// Maximum number of messages that can be sent in a burst.
+    pub capacity: u64,
+    /// Seconds to refill one message.
+    pub seconds_per_token: u64,
+}
+
+impl RateLimitParams {
+    pub const LEN: usize =
+          8 // capacity
+        + 8 // seconds_per_token
+    ;
+
+    /// Whether these parameters limit sending at all.
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+}
+
+#[account]
+#[derive(Default)]
+/// Token bucket account. There is one global bucket and one bucket per sender
+/// of [`send_message`](crate::hello_world::send_message).
+pub struct RateLimit {
+    /// Messages that can be sent before the bucket is empty.
+    pub tokens: u64,
+    /// Timestamp of the last refill. Zero if the bucket has never been used.
+    pub last_refill: i64,
+}
+
+impl RateLimit {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 8 // tokens
+        + 8 // last_refill
+    ;
+    /// AKA `b"rate_limit"`.
+    pub const SEED_PREFIX: &'static [u8; 10] = b"rate_limit";
+
+    /// Refill the bucket and take one token. If the bucket is empty, returns
+    /// the seconds until the next token is refilled instead.
+    pub fn try_consume(
+        &mut self,
+        params: &RateLimitParams,
+        now: i64,
+    ) -> std::result::Result<(), u64> {
+        if !params.is_enabled() {
+            return Ok(());
+        }
+
+        // A new bucket starts full.
+        if self.last_refill == 0 {
+            self.tokens = params.capacity;
+            self.last_refill = now;
+        }
+
+        let elapsed = now.saturating_sub(self.last_refill).max(0) as u64;
+        let refilled = elapsed / params.seconds_per_token;
+        self.tokens = self.tokens.saturating_add(refilled).min(params.capacity);
+        if self.tokens == params.capacity {
+            self.last_refill = now;
+        } else {
+            self.last_refill += (refilled * params.seconds_per_token) as i64;
+        }
+
+        if self.tokens == 0 {
+            let since_refill = now.saturating_sub(self.last_refill).max(0) as u64;
+            return Err(params.seconds_per_token.saturating_sub(since_refill));
+        }
+        self.tokens -= 1;
+        Ok(())
+    }
+
+    /// Same as [`RateLimit::try_consume`], but errors with
+    /// [`HelloWorldError::RateLimited`], whose message includes the seconds
+    /// until the next allowed send.
+    pub fn consume(&mut self, params: &RateLimitParams, now: i64) -> Result<()> {
+        self.try_consume(params, now)
+            .map_err(|wait| match error!(HelloWorldError::RateLimited) {
+                Error::AnchorError(mut err) => {
+                    err.error_msg = format!("Rate limited. Next send allowed in {wait} seconds");
+                    Error::AnchorError(err)
+                }
+                err => err,
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_rate_limit() -> Result<()> {
+        assert_eq!(RateLimitParams::LEN, size_of::<RateLimitParams>());
+        assert_eq!(
+            RateLimit::MAXIMUM_SIZE,
+            size_of::<u64>() + size_of::<u64>() + size_of::<i64>()
+        );
+
+        // No limit.
+        let mut bucket = RateLimit::default();
+        for _ in 0..10 {
+            assert_eq!(bucket.try_consume(&RateLimitParams::default(), 100), Ok(()));
+        }
+
+        // Burst of two, then one message every 60 seconds.
+        let params = RateLimitParams {
+            capacity: 2,
+            seconds_per_token: 60,
+        };
+        let now = 1_000;
+        assert_eq!(bucket.try_consume(&params, now), Ok(()));
+        assert_eq!(bucket.try_consume(&params, now), Ok(()));
+        assert_eq!(bucket.try_consume(&params, now), Err(60));
+        assert_eq!(bucket.try_consume(&params, now + 45), Err(15));
+        assert_eq!(bucket.try_consume(&params, now + 60), Ok(()));
+        assert_eq!(bucket.try_consume(&params, now + 90), Err(30));
+
+        // Refills up to capacity only.
+        assert_eq!(bucket.try_consume(&params, now + 10_000), Ok(()));
+        assert_eq!(bucket.try_consume(&params, now + 10_000), Ok(()));
+        assert_eq!(bucket.try_consume(&params, now + 10_000), Err(60));
+
+        // The error includes the seconds until the next allowed send.
+        match bucket.consume(&params, now + 10_015) {
+            Err(Error::AnchorError(err)) => {
+                assert_eq!(
+                    err.error_code_number,
+                    u32::from(HelloWorldError::RateLimited)
+                );
+                assert_eq!(
+                    err.error_msg,
+                    "Rate limited. Next send allowed in 45 seconds"
+                );
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+
+        Ok(())
+    }
+}
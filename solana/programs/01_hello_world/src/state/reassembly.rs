@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+
+use crate::message::CHUNK_MAX_LENGTH;
+
+/// Maximum number of chunks in a chunked message.
+pub const MAX_CHUNKS: u16 = 16;
+
+/// Seconds after the last received chunk when an incomplete reassembly is
+/// considered abandoned.
+pub const REASSEMBLY_TIMEOUT: i64 = 24 * 60 * 60;
+
+#[account]
+#[derive(Default)]
+/// Reassembly account. Chunks of a chunked message are written here as they
+/// are received (in any order). Once every chunk is received and the hash is
+/// verified, this account holds the complete message. Closing it shrinks it to
+/// a tombstone, so that chunks with its message ID cannot be received again.
+pub struct Reassembly {
+    /// Payer of this account's rent (whoever received the first chunk).
+    pub payer: Pubkey,
+    /// Message ID shared by every chunk.
+    pub message_id: u64,
+    /// Number of chunks in the message.
+    pub total_chunks: u16,
+    /// Bitmask of received chunk indices.
+    pub received_chunks: u16,
+    /// Keccak256 hash of the complete message.
+    pub hash: [u8; 32],
+    /// Whether every chunk was received and the hash was verified.
+    pub complete: bool,
+    /// Whether every chunk was received but the reassembled message did not
+    /// match the hash.
+    pub failed: bool,
+    /// Whether this account was closed and is now a tombstone.
+    pub closed: bool,
+    /// Timestamp of the last received chunk.
+    pub last_updated: i64,
+    /// Chunk `i` is written at offset `i * CHUNK_MAX_LENGTH`. Complete once
+    /// [`Reassembly::complete`] is true.
+    pub message: Vec<u8>,
+}
+
+impl Reassembly {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+        + 32 // payer
+        + 8 // message_id
+        + 2 // total_chunks
+        + 2 // received_chunks
+        + 32 // hash
+        + 1 // complete
+        + 1 // failed
+        + 1 // closed
+        + 8 // last_updated
+        + 4 // Vec length
+        + MAX_CHUNKS as usize * CHUNK_MAX_LENGTH // message
+    ;
+    /// Size of a closed Reassembly account, which has an empty message. It is
+    /// kept so that chunks with its message ID cannot be received again.
+    pub const TOMBSTONE_SIZE: usize = Self::MAXIMUM_SIZE - MAX_CHUNKS as usize * CHUNK_MAX_LENGTH;
+    /// AKA `b"reassembly"`.
+    pub const SEED_PREFIX: &'static [u8; 10] = b"reassembly";
+
+    /// Whether a chunk with this index, total and length can belong to a
+    /// chunked message.
+    pub fn is_valid_chunk(index: u16, total: u16, len: usize) -> bool {
+        total > 0
+            && total <= MAX_CHUNKS
+            && index < total
+            && len > 0
+            && (index == total - 1 || len == CHUNK_MAX_LENGTH)
+    }
+
+    /// Whether no chunk has been written to this account yet.
+    pub fn is_new(&self) -> bool {
+        self.total_chunks == 0
+    }
+
+    /// Whether the chunk with this index was already written.
+    pub fn has_chunk(&self, index: u16) -> bool {
+        self.received_chunks & (1 << index) != 0
+    }
+
+    /// Whether every chunk was written.
+    pub fn has_all_chunks(&self) -> bool {
+        self.received_chunks.count_ones() == u32::from(self.total_chunks)
+    }
+
+    /// Write a chunk at its offset in the message. Every chunk except the last
+    /// must be [`CHUNK_MAX_LENGTH`] bytes.
+    pub fn write_chunk(&mut self, index: u16, data: &[u8]) {
+        let offset = usize::from(index) * CHUNK_MAX_LENGTH;
+        let end = offset + data.len();
+        if self.message.len() < end {
+            self.message.resize(end, 0);
+        }
+        self.message[offset..end].copy_from_slice(data);
+        self.received_chunks |= 1 << index;
+    }
+
+    /// Whether every chunk was received, whether or not the reassembled
+    /// message matched the hash.
+    pub fn is_finished(&self) -> bool {
+        self.complete || self.failed
+    }
+
+    /// Whether this reassembly is incomplete and has not received a chunk
+    /// within [`REASSEMBLY_TIMEOUT`].
+    pub fn is_abandoned(&self, now: i64) -> bool {
+        !self.complete && now.saturating_sub(self.last_updated) >= REASSEMBLY_TIMEOUT
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_reassembly() -> Result<()> {
+        assert_eq!(
+            Reassembly::MAXIMUM_SIZE,
+            size_of::<u64>()
+                + size_of::<Pubkey>()
+                + size_of::<u64>()
+                + size_of::<u16>()
+                + size_of::<u16>()
+                + size_of::<[u8; 32]>()
+                + size_of::<bool>()
+                + size_of::<bool>()
+                + size_of::<bool>()
+                + size_of::<i64>()
+                + size_of::<u32>()
+                + MAX_CHUNKS as usize * CHUNK_MAX_LENGTH
+        );
+        assert!(u16::BITS >= u32::from(MAX_CHUNKS));
+
+        assert!(Reassembly::is_valid_chunk(0, 1, 1));
+        assert!(Reassembly::is_valid_chunk(0, 2, CHUNK_MAX_LENGTH));
+        assert!(!Reassembly::is_valid_chunk(0, 2, CHUNK_MAX_LENGTH - 1));
+        assert!(!Reassembly::is_valid_chunk(1, 1, 1));
+        assert!(!Reassembly::is_valid_chunk(0, 0, 1));
+        assert!(!Reassembly::is_valid_chunk(
+            0,
+            MAX_CHUNKS + 1,
+            CHUNK_MAX_LENGTH
+        ));
+        assert!(!Reassembly::is_valid_chunk(0, 1, 0));
+
+        let mut reassembly = Reassembly::default();
+        assert!(reassembly.is_new());
+        reassembly.total_chunks = 3;
+
+        // Write chunks out of order.
+        reassembly.write_chunk(2, &[3; 10]);
+        reassembly.write_chunk(0, &[1; CHUNK_MAX_LENGTH]);
+        assert!(reassembly.has_chunk(0));
+        assert!(!reassembly.has_chunk(1));
+        assert!(!reassembly.has_all_chunks());
+
+        reassembly.write_chunk(1, &[2; CHUNK_MAX_LENGTH]);
+        assert!(reassembly.has_all_chunks());
+
+        let expected = [
+            vec![1; CHUNK_MAX_LENGTH],
+            vec![2; CHUNK_MAX_LENGTH],
+            vec![3; 10],
+        ]
+        .concat();
+        assert_eq!(reassembly.message, expected);
+
+        // Abandoned only if incomplete and stale.
+        reassembly.last_updated = 100;
+        assert!(!reassembly.is_abandoned(100 + REASSEMBLY_TIMEOUT - 1));
+        assert!(reassembly.is_abandoned(100 + REASSEMBLY_TIMEOUT));
+        reassembly.complete = true;
+        assert!(reassembly.is_finished());
+        assert!(!reassembly.is_abandoned(100 + REASSEMBLY_TIMEOUT));
+
+        // A closed reassembly has an empty message and fits in a tombstone.
+        reassembly.message.clear();
+        reassembly.closed = true;
+        let mut data = Vec::new();
+        reassembly.try_serialize(&mut data)?;
+        assert_eq!(data.len(), Reassembly::TOMBSTONE_SIZE);
+
+        Ok(())
+    }
+}
@@ -4,14 +4,23 @@ pub const MESSAGE_MAX_LENGTH: usize = 1024;
 
 #[account]
 #[derive(Default)]
-/// Received account.
+/// Received account. After the message is processed, the payer can shrink
+/// this account to a tombstone with
+/// [`close_received`](crate::hello_world::close_received) to reclaim rent.
 pub struct Received {
     /// AKA nonce. Should always be zero in this example, but we save it anyway.
     pub batch_id: u32,
     /// Keccak256 hash of verified Wormhole message.
     pub wormhole_message_hash: [u8; 32],
     /// HelloWorldMessage from [HelloWorldMessage::Hello](crate::message::HelloWorldMessage).
+    /// Empty for `Ack` and `Chunk` messages and once this account is a tombstone.
     pub message: Vec<u8>,
+    /// Payer of this account's rent, who can reclaim it. Legacy accounts
+    /// created before this field was added deserialize with the default
+    /// pubkey if their message is short enough to leave room for it, and do
+    /// not deserialize at all otherwise. Either way, their rent cannot be
+    /// reclaimed.
+    pub payer: Pubkey,
 }
 
 impl Received {
@@ -20,9 +29,23 @@ impl Received {
         + 32 // wormhole_message_hash
         + 4 // Vec length
         + MESSAGE_MAX_LENGTH // message
+        + 32 // payer
+    ;
+    /// Size of a closed Received account, which has an empty message. It is
+    /// kept so that the Wormhole message cannot be replayed.
+    pub const TOMBSTONE_SIZE: usize = 8 // discriminator
+        + 4 // batch_id
+        + 32 // wormhole_message_hash
+        + 4 // Vec length
+        + 32 // payer
     ;
     /// AKA `b"received"`.
     pub const SEED_PREFIX: &'static [u8; 8] = b"received";
+
+    /// Whether this account was created before its payer was recorded.
+    pub fn is_legacy(&self) -> bool {
+        self.payer == Pubkey::default()
+    }
 }
 
 #[cfg(test)]
@@ -39,8 +62,43 @@ mod test {
                 + size_of::<[u8; 32]>()
                 + size_of::<u32>()
                 + MESSAGE_MAX_LENGTH
+                + size_of::<Pubkey>()
         );
 
+        let mut received = Received {
+            batch_id: 0,
+            wormhole_message_hash: [69; 32],
+            message: b"All your base are belong to us".to_vec(),
+            payer: Pubkey::new_unique(),
+        };
+        received.message.clear();
+
+        let mut data = Vec::new();
+        received.try_serialize(&mut data)?;
+        assert_eq!(data.len(), Received::TOMBSTONE_SIZE);
+        assert!(!received.is_legacy());
+
+        // Legacy accounts were allocated without room for the payer, and the
+        // bytes after the message are zeroed.
+        const LEGACY_MAXIMUM_SIZE: usize = Received::MAXIMUM_SIZE - 32;
+        let mut data = Vec::new();
+        received.try_serialize(&mut data)?;
+        data.truncate(Received::TOMBSTONE_SIZE - 32);
+        data.resize(LEGACY_MAXIMUM_SIZE, 0);
+        let legacy = Received::try_deserialize(&mut data.as_slice())?;
+        assert!(legacy.is_legacy());
+
+        // Legacy accounts with a message too long to leave room for the payer
+        // do not deserialize.
+        let received = Received {
+            message: vec![1; MESSAGE_MAX_LENGTH],
+            ..received
+        };
+        let mut data = Vec::new();
+        received.try_serialize(&mut data)?;
+        data.truncate(LEGACY_MAXIMUM_SIZE);
+        assert!(Received::try_deserialize(&mut data.as_slice()).is_err());
+
         Ok(())
     }
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+/// Treasury account. Holds the application fees and the fees for requesting
+/// `Finalized` finality paid by senders (in lamports) until they are
+/// withdrawn with [`withdraw_fees`](crate::hello_world::withdraw_fees).
+pub struct Treasury {}
+
+impl Treasury {
+    pub const MAXIMUM_SIZE: usize = 8 // discriminator
+    ;
+    /// AKA `b"treasury"`.
+    pub const SEED_PREFIX: &'static [u8; 8] = b"treasury";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+
+    #[test]
+    fn test_treasury() -> Result<()> {
+        assert_eq!(Treasury::MAXIMUM_SIZE, size_of::<u64>());
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token::{Token, TokenAccount},
+    token_interface,
 };
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
@@ -176,17 +177,17 @@ pub struct RegisterForeignContract<'info> {
     pub foreign_contract: Box<Account<'info, ForeignContract>>,
 
     #[account(
-        seeds = [
-            &chain.to_be_bytes(),
-            token_bridge_foreign_endpoint.emitter_address.as_ref()
-        ],
-        bump,
-        seeds::program = token_bridge_program.key
+        constraint = token_bridge_foreign_endpoint.verify(
+            &token_bridge_foreign_endpoint.key(),
+            chain
+        ) @ HelloTokenError::InvalidTokenBridgeForeignEndpoint
     )]
     /// Token Bridge foreign endpoint. This account should really be one
     /// endpoint per chain, but Token Bridge's PDA allows for multiple
     /// endpoints for each chain. We store the proper endpoint for the
-    /// emitter chain.
+    /// emitter chain. This account only exists if the Token Bridge registered
+    /// the foreign chain via governance, and it must be the PDA derived from
+    /// the registered chain and emitter address.
     pub token_bridge_foreign_endpoint: Account<'info, token_bridge::EndpointRegistration>,
 
     /// Token Bridge program.
@@ -252,10 +253,14 @@ pub struct SendNativeTokensWithPayload<'info> {
     /// tokens to an unregistered Wormhole chain ID. Read-only.
     pub foreign_contract: Box<Account<'info, ForeignContract>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = token_bridge::require_supported_mint(&mint.to_account_info())?.is_supported()
+    )]
     /// Mint info. This is the SPL token that will be bridged over to the
-    /// foreign contract. Mutable.
-    pub mint: Box<Account<'info, Mint>>,
+    /// foreign contract. The Token Bridge does not support Token-2022 mints.
+    /// Mutable.
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
         mut,
@@ -414,12 +419,14 @@ pub struct RedeemNativeTransferWithPayload<'info> {
 
     #[account(
         //address = vaa.data().mint() // This is bugged at the moment.
-        constraint = mint.key() == vaa.data().mint()
+        constraint = mint.key() == vaa.data().mint(),
+        constraint = token_bridge::require_supported_mint(&mint.to_account_info())?.is_supported()
     )]
     /// Mint info. This is the SPL token that will be bridged over from the
     /// foreign contract. This must match the token address specified in the
-    /// signed Wormhole message. Read-only.
-    pub mint: Account<'info, Mint>,
+    /// signed Wormhole message. The Token Bridge does not support Token-2022
+    /// mints. Read-only.
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
     #[account(
         mut,
@@ -720,17 +727,15 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
 
     #[account(
         mut,
-        seeds = [
-            token_bridge::WrappedMint::SEED_PREFIX,
-            &vaa.data().token_chain().to_be_bytes(),
-            vaa.data().token_address()
-        ],
-        bump,
-        seeds::program = token_bridge_program.key
+        constraint = matches!(
+            vaa.data().resolve_mint(Some(&token_bridge_wrapped_meta))?,
+            token_bridge::ResolvedMint::Wrapped { mint, .. } if mint == token_bridge_wrapped_mint.key()
+        ) @ HelloTokenError::InvalidWrappedMint
     )]
     /// Token Bridge wrapped mint info. This is the SPL token that will be
     /// bridged from the foreign contract. The wrapped mint PDA must agree
-    /// with the native token's metadata in the wormhole message. Mutable.
+    /// with the native token's metadata in the wormhole message, so tokens
+    /// native to Solana are rejected. Mutable.
     pub token_bridge_wrapped_mint: Box<Account<'info, token_bridge::WrappedMint>>,
 
     #[account(
@@ -770,19 +775,14 @@ pub struct RedeemWrappedTransferWithPayload<'info> {
     /// Token Bridge program.
     pub token_bridge_program: Program<'info, TokenBridge>,
 
-    #[account(
-        seeds = [
-            token_bridge::WrappedMeta::SEED_PREFIX,
-            token_bridge_wrapped_mint.key().as_ref()
-        ],
-        bump,
-        seeds::program = token_bridge_program.key
-    )]
     /// Token Bridge program's wrapped metadata, which stores info
     /// about the token from its native chain:
     ///   * Wormhole Chain ID
     ///   * Token's native contract address
     ///   * Token's native decimals
+    ///
+    /// This account is verified when resolving the wrapped mint from the
+    /// token info in the wormhole message.
     pub token_bridge_wrapped_meta: Account<'info, token_bridge::WrappedMeta>,
 
     #[account(
@@ -108,4 +108,9 @@ pub enum HelloTokenError {
     #[msg("InvalidTokenBridgeMintAuthority")]
     /// Specified Token Bridge mint authority PDA is wrong.
     InvalidTokenBridgeMintAuthority,
+
+    #[msg("InvalidWrappedMint")]
+    /// Specified Token Bridge wrapped mint disagrees with the token info in
+    /// the Token Bridge's Wormhole message.
+    InvalidWrappedMint,
 }
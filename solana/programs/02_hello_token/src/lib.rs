@@ -132,13 +132,15 @@ pub mod hello_token {
         // Token Bridge program truncates amounts to 8 decimals, so there will
         // be a residual amount if decimals of SPL is >8. We need to take into
         // account how much will actually be bridged.
-        let truncated_amount = token_bridge::truncate_amount(amount, ctx.accounts.mint.decimals);
+        let truncated_amount =
+            token_bridge::checked_truncate_amount(amount, ctx.accounts.mint.decimals)?;
         require!(truncated_amount > 0, HelloTokenError::ZeroBridgeAmount);
         if truncated_amount != amount {
             msg!(
-                "SendNativeTokensWithPayload :: truncating amount {} to {}",
+                "SendNativeTokensWithPayload :: truncating amount {} to {} (dust {})",
                 amount,
-                truncated_amount
+                truncated_amount,
+                amount - truncated_amount
             );
         }
 
@@ -154,10 +156,8 @@ pub mod hello_token {
         //     of truncated_amount.
         // 2.  Sign Token Bridge program's transfer_native instruction.
         // 3.  Close tmp_token_account.
-        let config_seeds = &[
-            SenderConfig::SEED_PREFIX.as_ref(),
-            &[ctx.accounts.config.bump],
-        ];
+        let authority = ctx.accounts.config.authority();
+        let config_seeds = authority.signer_seeds();
 
         // First transfer tokens from payer to tmp_token_account.
         anchor_spl::token::transfer(
@@ -278,10 +278,8 @@ pub mod hello_token {
         // 2.  Transfer tokens to relayer if he exists.
         // 3.  Transfer remaining tokens to recipient.
         // 4.  Close tmp_token_account.
-        let config_seeds = &[
-            RedeemerConfig::SEED_PREFIX.as_ref(),
-            &[ctx.accounts.config.bump],
-        ];
+        let authority = ctx.accounts.config.authority();
+        let config_seeds = authority.signer_seeds();
 
         // Redeem the token transfer.
         token_bridge::complete_transfer_native_with_payload(CpiContext::new_with_signer(
@@ -305,10 +303,12 @@ pub mod hello_token {
             &[&config_seeds[..]],
         ))?;
 
-        let amount = token_bridge::denormalize_amount(
-            ctx.accounts.vaa.data().amount(),
-            ctx.accounts.mint.decimals,
-        );
+        let amount = ctx
+            .accounts
+            .vaa
+            .data()
+            .normalized_amount()
+            .to_native(ctx.accounts.mint.decimals)?;
 
         // If this instruction were executed by a relayer, send some of the
         // token amount (determined by the relayer fee) to the payer's token
@@ -405,10 +405,8 @@ pub mod hello_token {
         //     of amount.
         // 2.  Sign Token Bridge program's transfer_wrapped instruction.
         // 3.  Close tmp_token_account.
-        let config_seeds = &[
-            SenderConfig::SEED_PREFIX.as_ref(),
-            &[ctx.accounts.config.bump],
-        ];
+        let authority = ctx.accounts.config.authority();
+        let config_seeds = authority.signer_seeds();
 
         // First transfer tokens from payer to tmp_token_account.
         anchor_spl::token::transfer(
@@ -529,10 +527,8 @@ pub mod hello_token {
         // 2.  Transfer tokens to relayer if he exists.
         // 3.  Transfer remaining tokens to recipient.
         // 4.  Close tmp_token_account.
-        let config_seeds = &[
-            RedeemerConfig::SEED_PREFIX.as_ref(),
-            &[ctx.accounts.config.bump],
-        ];
+        let authority = ctx.accounts.config.authority();
+        let config_seeds = authority.signer_seeds();
 
         // Redeem the token transfer.
         token_bridge::complete_transfer_wrapped_with_payload(CpiContext::new_with_signer(
@@ -44,6 +44,11 @@ impl RedeemerConfig {
     /// AKA `b"redeemer"`.
     pub const SEED_PREFIX: &'static [u8; 8] = token_bridge::SEED_PREFIX_REDEEMER;
 
+    /// Token Bridge redeemer authority, which signs for inbound transfers.
+    pub fn authority(&self) -> token_bridge::RedeemerAuthority {
+        token_bridge::RedeemerAuthority::new(self.bump)
+    }
+
     pub fn compute_relayer_amount(&self, amount: u64) -> u64 {
         (amount * self.relayer_fee as u64) / self.relayer_fee_precision as u64
     }
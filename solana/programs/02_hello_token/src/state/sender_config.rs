@@ -52,6 +52,11 @@ impl SenderConfig {
     ;
     /// AKA `b"sender"`.
     pub const SEED_PREFIX: &'static [u8; 6] = token_bridge::SEED_PREFIX_SENDER;
+
+    /// Token Bridge sender authority, which signs for outbound transfers.
+    pub fn authority(&self) -> token_bridge::SenderAuthority {
+        token_bridge::SenderAuthority::new(self.bump)
+    }
 }
 
 #[cfg(test)]
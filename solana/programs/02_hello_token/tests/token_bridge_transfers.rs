@@ -0,0 +1,822 @@
+//! Send and redeem Hello Token transfers end to end against the mock Core
+//! Bridge and mock Token Bridge, which are loaded as programs in
+//! `solana-program-test` together with the SPL Token programs.
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::ProgramResult, instruction::Instruction, program_pack::Pack,
+        system_instruction, sysvar,
+    },
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, spl_associated_token_account},
+    token::spl_token,
+};
+use hello_token::{
+    ForeignContract, HelloTokenError, HelloTokenMessage, RedeemerConfig, SenderConfig,
+    SEED_PREFIX_BRIDGED, SEED_PREFIX_TMP,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError,
+    signature::{Keypair, Signer as _},
+    transaction::{Transaction, TransactionError},
+};
+use wormhole_anchor_sdk::{
+    token_bridge::{self, TransferHeader},
+    wormhole::{self, CHAIN_ID_SOLANA},
+};
+
+const FOREIGN_CHAIN: u16 = 2;
+const FOREIGN_TOKEN_BRIDGE: [u8; 32] = [0x7b; 32];
+const FOREIGN_CONTRACT: [u8; 32] = [0xfc; 32];
+const FOREIGN_TOKEN: [u8; 32] = [0xee; 32];
+
+const RELAYER_FEE: u32 = 1;
+const RELAYER_FEE_PRECISION: u32 = 100;
+
+/// Anchor's entrypoint ties the account infos to the lifetime of their data,
+/// which `processor!` cannot express, so the account infos are leaked.
+fn hello_token_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    hello_token::entry(program_id, Box::leak(Box::new(accounts.to_vec())), data)
+}
+
+struct Fixture {
+    context: ProgramTestContext,
+    foreign_sequence: u64,
+}
+
+impl Fixture {
+    /// Deploy and initialize the mocks and Hello Token, and register the
+    /// foreign Token Bridge and Hello Token contract.
+    async fn new() -> Self {
+        let mut program_test = ProgramTest::new(
+            "mock_core_bridge",
+            wormhole::program::ID,
+            processor!(mock_core_bridge::process_instruction),
+        );
+        program_test.add_program(
+            "mock_token_bridge",
+            token_bridge::program::ID,
+            processor!(mock_token_bridge::process_instruction),
+        );
+        program_test.add_program(
+            "hello_token",
+            hello_token::ID,
+            processor!(hello_token_entry),
+        );
+
+        // The Token Bridge has already emitted messages on a live cluster, so
+        // its sequence account exists before Hello Token is initialized.
+        let data = wormhole::SequenceTracker { sequence: 0 }
+            .try_to_vec()
+            .unwrap();
+        program_test.add_account(
+            token_bridge_sequence(),
+            solana_sdk::account::Account {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: wormhole::program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let mut fixture = Self {
+            context: program_test.start_with_context().await,
+            foreign_sequence: 0,
+        };
+        let payer = fixture.payer();
+
+        fixture
+            .send(
+                mock_core_bridge::initialize(&wormhole::program::ID, &payer, 0).unwrap(),
+                &[],
+            )
+            .await
+            .unwrap();
+        fixture
+            .send(
+                mock_token_bridge::initialize(
+                    &token_bridge::program::ID,
+                    &payer,
+                    &wormhole::program::ID,
+                )
+                .unwrap(),
+                &[],
+            )
+            .await
+            .unwrap();
+        fixture
+            .send(
+                mock_token_bridge::register_chain(
+                    &token_bridge::program::ID,
+                    &payer,
+                    FOREIGN_CHAIN,
+                    FOREIGN_TOKEN_BRIDGE,
+                )
+                .unwrap(),
+                &[],
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .process(
+                hello_token::accounts::Initialize {
+                    owner: payer,
+                    sender_config: sender_config(),
+                    redeemer_config: redeemer_config(),
+                    wormhole_program: wormhole::program::ID,
+                    token_bridge_program: token_bridge::program::ID,
+                    token_bridge_config: mock_token_bridge::config_address(
+                        &token_bridge::program::ID,
+                    ),
+                    token_bridge_authority_signer: token_bridge_pda(
+                        token_bridge::SEED_PREFIX_AUTHORITY_SIGNER,
+                    ),
+                    token_bridge_custody_signer: token_bridge_pda(
+                        token_bridge::SEED_PREFIX_CUSTODY_SIGNER,
+                    ),
+                    token_bridge_mint_authority: token_bridge_pda(
+                        token_bridge::SEED_PREFIX_MINT_AUTHORITY,
+                    ),
+                    wormhole_bridge: mock_core_bridge::bridge_address(&wormhole::program::ID),
+                    token_bridge_emitter: token_bridge_emitter(),
+                    wormhole_fee_collector: mock_core_bridge::fee_collector_address(
+                        &wormhole::program::ID,
+                    ),
+                    token_bridge_sequence: token_bridge_sequence(),
+                    system_program: System::id(),
+                },
+                hello_token::instruction::Initialize {
+                    relayer_fee: RELAYER_FEE,
+                    relayer_fee_precision: RELAYER_FEE_PRECISION,
+                },
+                &[],
+            )
+            .await
+            .unwrap();
+        fixture
+            .process(
+                hello_token::accounts::RegisterForeignContract {
+                    owner: payer,
+                    config: sender_config(),
+                    foreign_contract: foreign_contract(),
+                    token_bridge_foreign_endpoint: mock_token_bridge::endpoint_address(
+                        &token_bridge::program::ID,
+                        FOREIGN_CHAIN,
+                        &FOREIGN_TOKEN_BRIDGE,
+                    ),
+                    token_bridge_program: token_bridge::program::ID,
+                    system_program: System::id(),
+                },
+                hello_token::instruction::RegisterForeignContract {
+                    chain: FOREIGN_CHAIN,
+                    address: FOREIGN_CONTRACT,
+                },
+                &[],
+            )
+            .await
+            .unwrap();
+
+        fixture
+    }
+
+    /// Fee payer of every transaction, which also owns Hello Token and the
+    /// native mints.
+    fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    /// Send a transaction with the instruction, signed by the fee payer and
+    /// the given signers.
+    async fn send(
+        &mut self,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), TransactionError> {
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            self.context.last_blockhash,
+        );
+        self.context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .map_err(|err| err.unwrap())
+    }
+
+    async fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), TransactionError> {
+        self.send(
+            Instruction {
+                program_id: hello_token::ID,
+                accounts: accounts.to_account_metas(None),
+                data: args.data(),
+            },
+            signers,
+        )
+        .await
+    }
+
+    async fn account(&mut self, address: &Pubkey) -> Option<solana_sdk::account::Account> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+    }
+
+    /// Balance of an SPL Token account, if it exists.
+    async fn token_balance(&mut self, address: &Pubkey) -> Option<u64> {
+        self.account(address).await.map(|account| {
+            spl_token::state::Account::unpack(&account.data)
+                .unwrap()
+                .amount
+        })
+    }
+
+    /// Create an SPL Token mint with the given decimals, whose mint authority
+    /// is the fee payer.
+    async fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+
+        self.send(
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            &[&mint],
+        )
+        .await
+        .unwrap();
+        self.send(
+            spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &payer,
+                None,
+                decimals,
+            )
+            .unwrap(),
+            &[],
+        )
+        .await
+        .unwrap();
+
+        mint.pubkey()
+    }
+
+    /// Create a funded wallet with an associated token account for the mint.
+    /// Minting requires the fee payer to be the mint authority.
+    async fn wallet(&mut self, mint: &Pubkey, amount: u64) -> (Keypair, Pubkey) {
+        let wallet = Keypair::new();
+        let payer = self.payer();
+
+        self.send(
+            system_instruction::transfer(&payer, &wallet.pubkey(), 1_000_000_000),
+            &[],
+        )
+        .await
+        .unwrap();
+        self.send(
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &payer,
+                &wallet.pubkey(),
+                mint,
+                &spl_token::ID,
+            ),
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let ata = get_associated_token_address(&wallet.pubkey(), mint);
+        if amount > 0 {
+            self.send(
+                spl_token::instruction::mint_to(&spl_token::ID, mint, &ata, &payer, &[], amount)
+                    .unwrap(),
+                &[],
+            )
+            .await
+            .unwrap();
+        }
+
+        (wallet, ata)
+    }
+
+    /// Post a Token Bridge transfer to Hello Token from the registered foreign
+    /// contract and return the VAA hash.
+    async fn post_transfer(
+        &mut self,
+        token_chain: u16,
+        token_address: [u8; 32],
+        amount: u64,
+        recipient: &Pubkey,
+    ) -> [u8; 32] {
+        let mut payload = TransferHeader {
+            amount,
+            token_address,
+            token_chain,
+            to_address: redeemer_config().to_bytes(),
+            to_chain: CHAIN_ID_SOLANA,
+            from_address: FOREIGN_CONTRACT,
+        }
+        .try_to_vec()
+        .unwrap();
+        HelloTokenMessage::Hello {
+            recipient: recipient.to_bytes(),
+        }
+        .serialize(&mut payload)
+        .unwrap();
+
+        let vaa = mock_core_bridge::VaaBody {
+            timestamp: 1,
+            nonce: 0,
+            emitter_chain: FOREIGN_CHAIN,
+            emitter_address: FOREIGN_TOKEN_BRIDGE,
+            sequence: self.foreign_sequence,
+            consistency_level: 1,
+            payload,
+        };
+        self.foreign_sequence += 1;
+        let payer = self.payer();
+        self.send(
+            mock_core_bridge::post_vaa(&wormhole::program::ID, &payer, &vaa).unwrap(),
+            &[],
+        )
+        .await
+        .unwrap();
+
+        vaa.hash()
+    }
+
+    async fn claim_address(&mut self, vaa_hash: &[u8; 32]) -> Pubkey {
+        let vaa = self.posted_transfer(&posted_vaa_address(vaa_hash)).await;
+        mock_token_bridge::claim_address(
+            &token_bridge::program::ID,
+            vaa.emitter_chain(),
+            vaa.emitter_address(),
+            vaa.sequence(),
+        )
+    }
+
+    /// Message account that the next outbound transfer will be posted to.
+    async fn next_message(&mut self) -> Pubkey {
+        let account = self.account(&token_bridge_sequence()).await.unwrap();
+        let tracker = wormhole::SequenceTracker::try_deserialize(&mut &account.data[..]).unwrap();
+        Pubkey::find_program_address(
+            &[SEED_PREFIX_BRIDGED, &tracker.next_value().to_le_bytes()],
+            &hello_token::ID,
+        )
+        .0
+    }
+
+    /// Read a posted message or VAA, whose data is prefixed with `b"msg"` or
+    /// `b"vaa"`.
+    async fn posted_transfer(&mut self, address: &Pubkey) -> hello_token::PostedHelloTokenMessage {
+        let account = self.account(address).await.unwrap();
+        assert_eq!(account.owner, wormhole::program::ID);
+        AnchorDeserialize::deserialize(&mut &account.data[3..]).unwrap()
+    }
+}
+
+/// Error of a transaction whose only instruction failed with the Hello Token
+/// error.
+fn hello_token_error(err: HelloTokenError) -> TransactionError {
+    TransactionError::InstructionError(0, InstructionError::Custom(err.into()))
+}
+
+fn sender_config() -> Pubkey {
+    Pubkey::find_program_address(&[SenderConfig::SEED_PREFIX], &hello_token::ID).0
+}
+
+fn redeemer_config() -> Pubkey {
+    Pubkey::find_program_address(&[RedeemerConfig::SEED_PREFIX], &hello_token::ID).0
+}
+
+fn foreign_contract() -> Pubkey {
+    Pubkey::find_program_address(
+        &[ForeignContract::SEED_PREFIX, &FOREIGN_CHAIN.to_le_bytes()],
+        &hello_token::ID,
+    )
+    .0
+}
+
+fn tmp_token_account(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SEED_PREFIX_TMP, mint.as_ref()], &hello_token::ID).0
+}
+
+fn token_bridge_pda(seed: &[u8]) -> Pubkey {
+    Pubkey::find_program_address(&[seed], &token_bridge::program::ID).0
+}
+
+fn token_bridge_emitter() -> Pubkey {
+    token_bridge_pda(token_bridge::SEED_PREFIX_EMITTER)
+}
+
+fn token_bridge_sequence() -> Pubkey {
+    mock_core_bridge::sequence_address(&wormhole::program::ID, &token_bridge_emitter())
+}
+
+fn posted_vaa_address(vaa_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wormhole::SEED_PREFIX_POSTED_VAA, vaa_hash],
+        &wormhole::program::ID,
+    )
+    .0
+}
+
+#[tokio::test]
+async fn native_transfer_round_trip() {
+    let mut fixture = Fixture::new().await;
+
+    // Native mint with more than 8 decimals, so that outbound amounts are
+    // truncated.
+    let mint = fixture.create_mint(9).await;
+    let (sender, sender_ata) = fixture.wallet(&mint, 10_000_000_000).await;
+    let custody = mock_token_bridge::custody_address(&token_bridge::program::ID, &mint);
+    let foreign_recipient = [0xaa; 32];
+
+    // Send 1.234567891 tokens to the foreign contract. The dust stays with the
+    // sender.
+    let message = fixture.next_message().await;
+    fixture
+        .process(
+            hello_token::accounts::SendNativeTokensWithPayload {
+                payer: sender.pubkey(),
+                config: sender_config(),
+                foreign_contract: foreign_contract(),
+                mint,
+                from_token_account: sender_ata,
+                tmp_token_account: tmp_token_account(&mint),
+                wormhole_program: wormhole::program::ID,
+                token_bridge_program: token_bridge::program::ID,
+                token_bridge_config: mock_token_bridge::config_address(&token_bridge::program::ID),
+                token_bridge_custody: custody,
+                token_bridge_authority_signer: token_bridge_pda(
+                    token_bridge::SEED_PREFIX_AUTHORITY_SIGNER,
+                ),
+                token_bridge_custody_signer: token_bridge_pda(
+                    token_bridge::SEED_PREFIX_CUSTODY_SIGNER,
+                ),
+                wormhole_bridge: mock_core_bridge::bridge_address(&wormhole::program::ID),
+                wormhole_message: message,
+                token_bridge_emitter: token_bridge_emitter(),
+                token_bridge_sequence: token_bridge_sequence(),
+                wormhole_fee_collector: mock_core_bridge::fee_collector_address(
+                    &wormhole::program::ID,
+                ),
+                system_program: System::id(),
+                token_program: anchor_spl::token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                clock: sysvar::clock::ID,
+                rent: sysvar::rent::ID,
+            },
+            hello_token::instruction::SendNativeTokensWithPayload {
+                batch_id: 0,
+                amount: 1_234_567_891,
+                recipient_address: foreign_recipient,
+                recipient_chain: FOREIGN_CHAIN,
+            },
+            &[&sender],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        fixture.token_balance(&sender_ata).await,
+        Some(8_765_432_110)
+    );
+    assert_eq!(fixture.token_balance(&custody).await, Some(1_234_567_890));
+    assert!(fixture.account(&tmp_token_account(&mint)).await.is_none());
+
+    // The Token Bridge posted the transfer with Hello Token's payload.
+    let posted = fixture.posted_transfer(&message).await;
+    assert_eq!(*posted.emitter_address(), token_bridge_emitter().to_bytes());
+    let transfer = posted.data();
+    assert_eq!(transfer.amount(), 123_456_789);
+    assert_eq!(transfer.mint(), mint);
+    assert_eq!(*transfer.to_address(), FOREIGN_CONTRACT);
+    assert_eq!(transfer.to_chain(), FOREIGN_CHAIN);
+    assert_eq!(*transfer.from_address(), hello_token::ID.to_bytes());
+    let HelloTokenMessage::Hello { recipient } = transfer.message();
+    assert_eq!(*recipient, foreign_recipient);
+
+    // A relayer redeems 1 token back to a recipient, and is paid 1% of it.
+    let (relayer, relayer_ata) = fixture.wallet(&mint, 0).await;
+    let (recipient, recipient_ata) = fixture.wallet(&mint, 0).await;
+    let vaa_hash = fixture
+        .post_transfer(
+            CHAIN_ID_SOLANA,
+            mint.to_bytes(),
+            100_000_000,
+            &recipient.pubkey(),
+        )
+        .await;
+    let claim = fixture.claim_address(&vaa_hash).await;
+    let redeem = || hello_token::accounts::RedeemNativeTransferWithPayload {
+        payer: relayer.pubkey(),
+        payer_token_account: relayer_ata,
+        config: redeemer_config(),
+        foreign_contract: foreign_contract(),
+        mint,
+        recipient_token_account: recipient_ata,
+        recipient: recipient.pubkey(),
+        tmp_token_account: tmp_token_account(&mint),
+        wormhole_program: wormhole::program::ID,
+        token_bridge_program: token_bridge::program::ID,
+        token_bridge_config: mock_token_bridge::config_address(&token_bridge::program::ID),
+        vaa: posted_vaa_address(&vaa_hash),
+        token_bridge_claim: claim,
+        token_bridge_foreign_endpoint: mock_token_bridge::endpoint_address(
+            &token_bridge::program::ID,
+            FOREIGN_CHAIN,
+            &FOREIGN_TOKEN_BRIDGE,
+        ),
+        token_bridge_custody: custody,
+        token_bridge_custody_signer: token_bridge_pda(token_bridge::SEED_PREFIX_CUSTODY_SIGNER),
+        system_program: System::id(),
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        rent: sysvar::rent::ID,
+    };
+    fixture
+        .process(
+            redeem(),
+            hello_token::instruction::RedeemNativeTransferWithPayload {
+                _vaa_hash: vaa_hash,
+            },
+            &[&relayer],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(fixture.token_balance(&relayer_ata).await, Some(10_000_000));
+    assert_eq!(
+        fixture.token_balance(&recipient_ata).await,
+        Some(990_000_000)
+    );
+    assert_eq!(fixture.token_balance(&custody).await, Some(234_567_890));
+    assert!(fixture.account(&claim).await.is_some());
+    assert!(fixture.account(&tmp_token_account(&mint)).await.is_none());
+
+    // The transfer cannot be redeemed twice. A new blockhash keeps the same
+    // transaction from being dropped as a duplicate.
+    fixture.context.last_blockhash = fixture.context.get_new_latest_blockhash().await.unwrap();
+    assert_eq!(
+        fixture
+            .process(
+                redeem(),
+                hello_token::instruction::RedeemNativeTransferWithPayload {
+                    _vaa_hash: vaa_hash
+                },
+                &[&relayer],
+            )
+            .await,
+        Err(hello_token_error(HelloTokenError::AlreadyRedeemed))
+    );
+}
+
+#[tokio::test]
+async fn wrapped_transfer_round_trip() {
+    let mut fixture = Fixture::new().await;
+
+    // The foreign token has 18 decimals, so its wrapped mint has 8.
+    let payer = fixture.payer();
+    fixture
+        .send(
+            mock_token_bridge::create_wrapped(
+                &token_bridge::program::ID,
+                &payer,
+                FOREIGN_CHAIN,
+                FOREIGN_TOKEN,
+                18,
+            )
+            .unwrap(),
+            &[],
+        )
+        .await
+        .unwrap();
+    let wrapped_mint = mock_token_bridge::wrapped_mint_address(
+        &token_bridge::program::ID,
+        FOREIGN_CHAIN,
+        &FOREIGN_TOKEN,
+    );
+    let wrapped_meta =
+        mock_token_bridge::wrapped_meta_address(&token_bridge::program::ID, &wrapped_mint);
+
+    // The recipient redeems 0.5 wrapped tokens itself, so there is no relayer
+    // fee.
+    let (recipient, recipient_ata) = fixture.wallet(&wrapped_mint, 0).await;
+    let vaa_hash = fixture
+        .post_transfer(
+            FOREIGN_CHAIN,
+            FOREIGN_TOKEN,
+            50_000_000,
+            &recipient.pubkey(),
+        )
+        .await;
+    let claim = fixture.claim_address(&vaa_hash).await;
+    fixture
+        .process(
+            hello_token::accounts::RedeemWrappedTransferWithPayload {
+                payer: recipient.pubkey(),
+                payer_token_account: recipient_ata,
+                config: redeemer_config(),
+                foreign_contract: foreign_contract(),
+                token_bridge_wrapped_mint: wrapped_mint,
+                recipient_token_account: recipient_ata,
+                recipient: recipient.pubkey(),
+                tmp_token_account: tmp_token_account(&wrapped_mint),
+                wormhole_program: wormhole::program::ID,
+                token_bridge_program: token_bridge::program::ID,
+                token_bridge_wrapped_meta: wrapped_meta,
+                token_bridge_config: mock_token_bridge::config_address(&token_bridge::program::ID),
+                vaa: posted_vaa_address(&vaa_hash),
+                token_bridge_claim: claim,
+                token_bridge_foreign_endpoint: mock_token_bridge::endpoint_address(
+                    &token_bridge::program::ID,
+                    FOREIGN_CHAIN,
+                    &FOREIGN_TOKEN_BRIDGE,
+                ),
+                token_bridge_mint_authority: token_bridge_pda(
+                    token_bridge::SEED_PREFIX_MINT_AUTHORITY,
+                ),
+                system_program: System::id(),
+                token_program: anchor_spl::token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                rent: sysvar::rent::ID,
+            },
+            hello_token::instruction::RedeemWrappedTransferWithPayload {
+                _vaa_hash: vaa_hash,
+            },
+            &[&recipient],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.token_balance(&recipient_ata).await,
+        Some(50_000_000)
+    );
+
+    // Send 0.2 wrapped tokens back to the foreign contract, which burns them.
+    let foreign_recipient = [0xbb; 32];
+    let message = fixture.next_message().await;
+    fixture
+        .process(
+            hello_token::accounts::SendWrappedTokensWithPayload {
+                payer: recipient.pubkey(),
+                config: sender_config(),
+                foreign_contract: foreign_contract(),
+                token_bridge_wrapped_mint: wrapped_mint,
+                from_token_account: recipient_ata,
+                tmp_token_account: tmp_token_account(&wrapped_mint),
+                wormhole_program: wormhole::program::ID,
+                token_bridge_program: token_bridge::program::ID,
+                token_bridge_wrapped_meta: wrapped_meta,
+                token_bridge_config: mock_token_bridge::config_address(&token_bridge::program::ID),
+                token_bridge_authority_signer: token_bridge_pda(
+                    token_bridge::SEED_PREFIX_AUTHORITY_SIGNER,
+                ),
+                wormhole_bridge: mock_core_bridge::bridge_address(&wormhole::program::ID),
+                wormhole_message: message,
+                token_bridge_emitter: token_bridge_emitter(),
+                token_bridge_sequence: token_bridge_sequence(),
+                wormhole_fee_collector: mock_core_bridge::fee_collector_address(
+                    &wormhole::program::ID,
+                ),
+                system_program: System::id(),
+                token_program: anchor_spl::token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                clock: sysvar::clock::ID,
+                rent: sysvar::rent::ID,
+            },
+            hello_token::instruction::SendWrappedTokensWithPayload {
+                batch_id: 0,
+                amount: 20_000_000,
+                recipient_address: foreign_recipient,
+                recipient_chain: FOREIGN_CHAIN,
+            },
+            &[&recipient],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        fixture.token_balance(&recipient_ata).await,
+        Some(30_000_000)
+    );
+    assert!(fixture
+        .account(&tmp_token_account(&wrapped_mint))
+        .await
+        .is_none());
+
+    let posted = fixture.posted_transfer(&message).await;
+    let transfer = posted.data();
+    assert_eq!(transfer.amount(), 20_000_000);
+    assert_eq!(transfer.token_chain(), FOREIGN_CHAIN);
+    assert_eq!(*transfer.token_address(), FOREIGN_TOKEN);
+    assert_eq!(*transfer.to_address(), FOREIGN_CONTRACT);
+    let HelloTokenMessage::Hello { recipient } = transfer.message();
+    assert_eq!(*recipient, foreign_recipient);
+}
+
+#[tokio::test]
+async fn cannot_redeem_native_transfer_as_wrapped() {
+    let mut fixture = Fixture::new().await;
+
+    // A wrapped mint exists, so its metadata account can be passed along.
+    let payer = fixture.payer();
+    fixture
+        .send(
+            mock_token_bridge::create_wrapped(
+                &token_bridge::program::ID,
+                &payer,
+                FOREIGN_CHAIN,
+                FOREIGN_TOKEN,
+                18,
+            )
+            .unwrap(),
+            &[],
+        )
+        .await
+        .unwrap();
+    let wrapped_mint = mock_token_bridge::wrapped_mint_address(
+        &token_bridge::program::ID,
+        FOREIGN_CHAIN,
+        &FOREIGN_TOKEN,
+    );
+    let wrapped_meta =
+        mock_token_bridge::wrapped_meta_address(&token_bridge::program::ID, &wrapped_mint);
+
+    // The transfer is of a token native to Solana, so its mint is not a
+    // Token Bridge wrapped mint.
+    let mint = fixture.create_mint(9).await;
+    let (recipient, recipient_ata) = fixture.wallet(&mint, 0).await;
+    let vaa_hash = fixture
+        .post_transfer(
+            CHAIN_ID_SOLANA,
+            mint.to_bytes(),
+            100_000_000,
+            &recipient.pubkey(),
+        )
+        .await;
+    let claim = fixture.claim_address(&vaa_hash).await;
+    assert_eq!(
+        fixture
+            .process(
+                hello_token::accounts::RedeemWrappedTransferWithPayload {
+                    payer: recipient.pubkey(),
+                    payer_token_account: recipient_ata,
+                    config: redeemer_config(),
+                    foreign_contract: foreign_contract(),
+                    token_bridge_wrapped_mint: mint,
+                    recipient_token_account: recipient_ata,
+                    recipient: recipient.pubkey(),
+                    tmp_token_account: tmp_token_account(&mint),
+                    wormhole_program: wormhole::program::ID,
+                    token_bridge_program: token_bridge::program::ID,
+                    token_bridge_wrapped_meta: wrapped_meta,
+                    token_bridge_config: mock_token_bridge::config_address(
+                        &token_bridge::program::ID
+                    ),
+                    vaa: posted_vaa_address(&vaa_hash),
+                    token_bridge_claim: claim,
+                    token_bridge_foreign_endpoint: mock_token_bridge::endpoint_address(
+                        &token_bridge::program::ID,
+                        FOREIGN_CHAIN,
+                        &FOREIGN_TOKEN_BRIDGE,
+                    ),
+                    token_bridge_mint_authority: token_bridge_pda(
+                        token_bridge::SEED_PREFIX_MINT_AUTHORITY,
+                    ),
+                    system_program: System::id(),
+                    token_program: anchor_spl::token::ID,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    rent: sysvar::rent::ID,
+                },
+                hello_token::instruction::RedeemWrappedTransferWithPayload {
+                    _vaa_hash: vaa_hash,
+                },
+                &[&recipient],
+            )
+            .await,
+        Err(hello_token_error(HelloTokenError::InvalidWrappedMint))
+    );
+}
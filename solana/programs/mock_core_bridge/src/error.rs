@@ -0,0 +1,37 @@
+use anchor_lang::prelude::error_code;
+
+#[error_code]
+/// Errors relevant to the mock Core Bridge.
+pub enum MockCoreBridgeError {
+    #[msg("UnsupportedInstruction")]
+    /// Instruction is not implemented by the mock.
+    UnsupportedInstruction,
+
+    #[msg("InvalidBridge")]
+    /// Bridge account is not the expected PDA.
+    InvalidBridge,
+
+    #[msg("InvalidFeeCollector")]
+    /// Fee collector account is not the expected PDA.
+    InvalidFeeCollector,
+
+    #[msg("InvalidSequence")]
+    /// Sequence account is not the expected PDA for the emitter.
+    InvalidSequence,
+
+    #[msg("InvalidPostedVaa")]
+    /// Posted VAA account is not the expected PDA for the VAA body.
+    InvalidPostedVaa,
+
+    #[msg("EmitterNotSigner")]
+    /// Emitter did not sign for the message.
+    EmitterNotSigner,
+
+    #[msg("InsufficientFees")]
+    /// Message fee was not paid to the fee collector.
+    InsufficientFees,
+
+    #[msg("AlreadyInitialized")]
+    /// Account was already created.
+    AlreadyInitialized,
+}
@@ -0,0 +1,192 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{self, instruction::Instruction, keccak},
+};
+use wormhole_anchor_sdk::wormhole::{self, Finality};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+/// Mock Core Bridge instructions. Variants share the same order (and
+/// encoding, where implemented) as [`wormhole::Instruction`], so the SDK's CPI
+/// helpers can call this program.
+pub enum MockInstruction {
+    /// Create the bridge config and fund the fee collector. Unlike the real
+    /// Core Bridge, there is no guardian set.
+    Initialize {
+        guardian_set_expiration_time: u32,
+        fee: u64,
+    },
+    PostMessage {
+        batch_id: u32,
+        payload: Vec<u8>,
+        finality: Finality,
+    },
+    /// Post a VAA without verifying guardian signatures.
+    PostVAA {
+        version: u8,
+        guardian_set_index: u32,
+        timestamp: u32,
+        nonce: u32,
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        sequence: u64,
+        consistency_level: u8,
+        payload: Vec<u8>,
+    },
+    SetFees,               // unsupported
+    TransferFees,          // unsupported
+    UpgradeContract,       // unsupported
+    UpgradeGuardianSet,    // unsupported
+    VerifySignatures,      // unsupported
+    PostMessageUnreliable, // unsupported
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// Body of a VAA to post with [`post_vaa`].
+pub struct VaaBody {
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+}
+
+impl VaaBody {
+    /// Keccak256 hash of the VAA body, which is what the Core Bridge uses to
+    /// derive the posted VAA account.
+    pub fn hash(&self) -> [u8; 32] {
+        keccak::hashv(&[
+            &self.timestamp.to_be_bytes(),
+            &self.nonce.to_be_bytes(),
+            &self.emitter_chain.to_be_bytes(),
+            &self.emitter_address,
+            &self.sequence.to_be_bytes(),
+            &[self.consistency_level],
+            &self.payload,
+        ])
+        .to_bytes()
+    }
+
+    /// Derive the posted VAA account for this body.
+    pub fn posted_vaa_address(&self, program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[wormhole::SEED_PREFIX_POSTED_VAA, &self.hash()],
+            program_id,
+        )
+        .0
+    }
+}
+
+/// Derive the bridge config account.
+pub fn bridge_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[wormhole::BridgeData::SEED_PREFIX], program_id).0
+}
+
+/// Derive the fee collector account.
+pub fn fee_collector_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[wormhole::FeeCollector::SEED_PREFIX], program_id).0
+}
+
+/// Derive the sequence account of an emitter.
+pub fn sequence_address(program_id: &Pubkey, emitter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wormhole::SequenceTracker::SEED_PREFIX, emitter.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+/// Build an instruction to initialize the mock Core Bridge.
+pub fn initialize(program_id: &Pubkey, payer: &Pubkey, fee: u64) -> Result<Instruction> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(bridge_address(program_id), false),
+            AccountMeta::new(fee_collector_address(program_id), false),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ],
+        data: MockInstruction::Initialize {
+            guardian_set_expiration_time: 86400,
+            fee,
+        }
+        .try_to_vec()?,
+    })
+}
+
+/// Build an instruction to post a VAA, which can then be consumed as a
+/// [`PostedVaa`](wormhole::PostedVaa) account at
+/// [`VaaBody::posted_vaa_address`].
+pub fn post_vaa(program_id: &Pubkey, payer: &Pubkey, vaa: &VaaBody) -> Result<Instruction> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(vaa.posted_vaa_address(program_id), false),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ],
+        data: MockInstruction::PostVAA {
+            version: 1,
+            guardian_set_index: 0,
+            timestamp: vaa.timestamp,
+            nonce: vaa.nonce,
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+            sequence: vaa.sequence,
+            consistency_level: vaa.consistency_level,
+            payload: vaa.payload.clone(),
+        }
+        .try_to_vec()?,
+    })
+}
+
+#[test]
+fn sdk_instruction_compatibility() {
+    let encoded = wormhole::Instruction::PostMessage {
+        batch_id: 69,
+        payload: b"All your base are belong to us.".to_vec(),
+        finality: Finality::Finalized,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    match MockInstruction::try_from_slice(&encoded).unwrap() {
+        MockInstruction::PostMessage {
+            batch_id,
+            payload,
+            finality,
+        } => {
+            assert_eq!(batch_id, 69);
+            assert_eq!(payload, b"All your base are belong to us.");
+            assert!(finality == Finality::Finalized);
+        }
+        _ => panic!("Expected PostMessage"),
+    }
+
+    let vaa = VaaBody {
+        emitter_chain: 2,
+        payload: vec![1, 2, 3],
+        ..Default::default()
+    };
+    let encoded = wormhole::Instruction::PostVAA {
+        version: 1,
+        guardian_set_index: 0,
+        timestamp: vaa.timestamp,
+        nonce: vaa.nonce,
+        emitter_chain: vaa.emitter_chain,
+        emitter_address: vaa.emitter_address,
+        sequence: vaa.sequence,
+        consistency_level: vaa.consistency_level,
+        payload: vaa.payload.clone(),
+    }
+    .try_to_vec()
+    .unwrap();
+    let program_id = Pubkey::new_unique();
+    assert_eq!(
+        post_vaa(&program_id, &Pubkey::new_unique(), &vaa)
+            .unwrap()
+            .data,
+        encoded
+    );
+}
@@ -0,0 +1,38 @@
+//! # Mock Core Bridge
+//!
+//! Minimal stand-in for the Wormhole Core Bridge program, meant to be
+//! deployed at the Core Bridge program ID (see
+//! [`wormhole::program::ID`](wormhole_anchor_sdk::wormhole::program::ID)) in
+//! local integration tests (e.g. `solana-program-test` or LiteSVM).
+//!
+//! Accounts written by this program use the same layout as the real Core
+//! Bridge, so they can be read with the SDK's [`BridgeData`],
+//! [`SequenceTracker`] and [`PostedVaa`] types.
+//!
+//! Guardian signatures are NOT verified. Instead, any payer can post a VAA
+//! directly with [`post_vaa`]. Never deploy this program outside of tests.
+//!
+//! [`BridgeData`]: wormhole_anchor_sdk::wormhole::BridgeData
+//! [`SequenceTracker`]: wormhole_anchor_sdk::wormhole::SequenceTracker
+//! [`PostedVaa`]: wormhole_anchor_sdk::wormhole::PostedVaa
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+pub use error::*;
+pub use instruction::*;
+
+pub mod error;
+pub mod instruction;
+mod processor;
+
+#[cfg(not(feature = "no-entrypoint"))]
+anchor_lang::solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    processor::process(program_id, accounts, instruction_data).map_err(Into::into)
+}
@@ -0,0 +1,281 @@
+use anchor_lang::{prelude::*, solana_program::account_info::next_account_info, system_program};
+use wormhole_anchor_sdk::wormhole::{self, BridgeConfig, BridgeData, PostedVaaData, PostedVaaMeta};
+
+use crate::{
+    error::MockCoreBridgeError,
+    instruction::{MockInstruction, VaaBody},
+};
+
+pub fn process<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    mut data: &[u8],
+) -> Result<()> {
+    match MockInstruction::deserialize(&mut data)? {
+        MockInstruction::Initialize {
+            guardian_set_expiration_time,
+            fee,
+        } => initialize(program_id, accounts, guardian_set_expiration_time, fee),
+        MockInstruction::PostMessage {
+            batch_id,
+            payload,
+            finality,
+        } => post_message(program_id, accounts, batch_id, payload, finality as u8),
+        MockInstruction::PostVAA {
+            version,
+            timestamp,
+            nonce,
+            emitter_chain,
+            emitter_address,
+            sequence,
+            consistency_level,
+            payload,
+            ..
+        } => post_vaa(
+            program_id,
+            accounts,
+            version,
+            VaaBody {
+                timestamp,
+                nonce,
+                emitter_chain,
+                emitter_address,
+                sequence,
+                consistency_level,
+                payload,
+            },
+        ),
+        _ => err!(MockCoreBridgeError::UnsupportedInstruction),
+    }
+}
+
+fn initialize<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    guardian_set_expiration_time: u32,
+    fee: u64,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let bridge = next_account_info(accounts)?;
+    let fee_collector = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+
+    let (expected, bridge_bump) =
+        Pubkey::find_program_address(&[BridgeData::SEED_PREFIX], program_id);
+    require_keys_eq!(bridge.key(), expected, MockCoreBridgeError::InvalidBridge);
+    let (expected, _) =
+        Pubkey::find_program_address(&[wormhole::FeeCollector::SEED_PREFIX], program_id);
+    require_keys_eq!(
+        fee_collector.key(),
+        expected,
+        MockCoreBridgeError::InvalidFeeCollector
+    );
+
+    // The fee collector is a system account, so it only needs to be funded.
+    let rent = Rent::get()?;
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: payer.clone(),
+                to: fee_collector.clone(),
+            },
+        ),
+        rent.minimum_balance(0),
+    )?;
+
+    let data = BridgeData {
+        guardian_set_index: 0,
+        last_lamports: fee_collector.lamports(),
+        config: BridgeConfig {
+            guardian_set_expiration_time,
+            fee,
+        },
+    }
+    .try_to_vec()?;
+    create_account(
+        payer,
+        bridge,
+        system_program,
+        program_id,
+        &data,
+        &[BridgeData::SEED_PREFIX, &[bridge_bump]],
+    )
+}
+
+fn post_message<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    batch_id: u32,
+    payload: Vec<u8>,
+    finality: u8,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let bridge = next_account_info(accounts)?;
+    let message = next_account_info(accounts)?;
+    let emitter = next_account_info(accounts)?;
+    let sequence = next_account_info(accounts)?;
+    let payer = next_account_info(accounts)?;
+    let fee_collector = next_account_info(accounts)?;
+    let _clock = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+
+    require!(emitter.is_signer, MockCoreBridgeError::EmitterNotSigner);
+    require_keys_eq!(
+        bridge.key(),
+        crate::bridge_address(program_id),
+        MockCoreBridgeError::InvalidBridge
+    );
+    require_keys_eq!(
+        fee_collector.key(),
+        crate::fee_collector_address(program_id),
+        MockCoreBridgeError::InvalidFeeCollector
+    );
+
+    // The fee must have been transferred to the fee collector before posting.
+    let mut bridge_data = BridgeData::try_deserialize(&mut &bridge.data.borrow()[..])?;
+    require!(
+        fee_collector.lamports() >= bridge_data.last_lamports + bridge_data.fee(),
+        MockCoreBridgeError::InsufficientFees
+    );
+    bridge_data.last_lamports = fee_collector.lamports();
+    bridge_data.serialize(&mut &mut bridge.data.borrow_mut()[..])?;
+
+    let (expected, sequence_bump) = Pubkey::find_program_address(
+        &[wormhole::SequenceTracker::SEED_PREFIX, emitter.key.as_ref()],
+        program_id,
+    );
+    require_keys_eq!(
+        sequence.key(),
+        expected,
+        MockCoreBridgeError::InvalidSequence
+    );
+    if sequence.data_is_empty() {
+        create_account(
+            payer,
+            sequence,
+            system_program,
+            program_id,
+            &wormhole::SequenceTracker::default().try_to_vec()?,
+            &[
+                wormhole::SequenceTracker::SEED_PREFIX,
+                emitter.key.as_ref(),
+                &[sequence_bump],
+            ],
+        )?;
+    }
+    let mut tracker = wormhole::SequenceTracker::try_deserialize(&mut &sequence.data.borrow()[..])?;
+
+    let posted = PostedVaaData {
+        meta: PostedVaaMeta {
+            version: 0,
+            finality,
+            timestamp: 0,
+            signature_set: Pubkey::default(),
+            posted_timestamp: Clock::get()?.unix_timestamp as u32,
+            batch_id,
+            sequence: tracker.value(),
+            emitter_chain: wormhole::CHAIN_ID_SOLANA,
+            emitter_address: emitter.key.to_bytes(),
+        },
+        payload,
+    };
+    let mut data = b"msg".to_vec();
+    posted.serialize(&mut data)?;
+
+    // The message account signs for its own creation, either as a keypair or
+    // as a PDA of the calling program.
+    require!(
+        message.data_is_empty(),
+        MockCoreBridgeError::AlreadyInitialized
+    );
+    create_account(payer, message, system_program, program_id, &data, &[])?;
+
+    tracker.sequence = tracker.next_value();
+    tracker.serialize(&mut &mut sequence.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+fn post_vaa<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    version: u8,
+    vaa: VaaBody,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let posted_vaa = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+
+    let hash = vaa.hash();
+    let (expected, bump) =
+        Pubkey::find_program_address(&[wormhole::SEED_PREFIX_POSTED_VAA, &hash], program_id);
+    require_keys_eq!(
+        posted_vaa.key(),
+        expected,
+        MockCoreBridgeError::InvalidPostedVaa
+    );
+    require!(
+        posted_vaa.data_is_empty(),
+        MockCoreBridgeError::AlreadyInitialized
+    );
+
+    // Signatures are not verified, so there is no signature set.
+    let posted = PostedVaaData {
+        meta: PostedVaaMeta {
+            version,
+            finality: vaa.consistency_level,
+            timestamp: vaa.timestamp,
+            signature_set: Pubkey::default(),
+            posted_timestamp: Clock::get()?.unix_timestamp as u32,
+            batch_id: vaa.nonce,
+            sequence: vaa.sequence,
+            emitter_chain: vaa.emitter_chain,
+            emitter_address: vaa.emitter_address,
+        },
+        payload: vaa.payload,
+    };
+    let mut data = b"vaa".to_vec();
+    posted.serialize(&mut data)?;
+    create_account(
+        payer,
+        posted_vaa,
+        system_program,
+        program_id,
+        &data,
+        &[wormhole::SEED_PREFIX_POSTED_VAA, &hash, &[bump]],
+    )
+}
+
+fn create_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: &Pubkey,
+    data: &[u8],
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let signers: &[&[&[u8]]] = if signer_seeds.is_empty() {
+        &[]
+    } else {
+        &[signer_seeds]
+    };
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+            signers,
+        ),
+        Rent::get()?.minimum_balance(data.len()),
+        data.len() as u64,
+        owner,
+    )?;
+    account.data.borrow_mut().copy_from_slice(data);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::error_code;
+
+#[error_code]
+/// Errors relevant to the mock Token Bridge.
+pub enum MockTokenBridgeError {
+    #[msg("UnsupportedInstruction")]
+    /// Instruction is not implemented by the mock.
+    UnsupportedInstruction,
+
+    #[msg("InvalidConfig")]
+    /// Config account is not the expected PDA.
+    InvalidConfig,
+
+    #[msg("InvalidPda")]
+    /// Account is not the expected PDA.
+    InvalidPda,
+
+    #[msg("InvalidWormholeProgram")]
+    /// Core Bridge program (or account owned by it) disagrees with the config.
+    InvalidWormholeProgram,
+
+    #[msg("InvalidForeignEndpoint")]
+    /// Foreign endpoint is not registered for the VAA's emitter.
+    InvalidForeignEndpoint,
+
+    #[msg("InvalidSender")]
+    /// Sender did not sign or is not the CPI program's sender PDA.
+    InvalidSender,
+
+    #[msg("InvalidRedeemer")]
+    /// Redeemer did not sign or is not allowed to redeem the transfer.
+    InvalidRedeemer,
+
+    #[msg("InvalidRecipient")]
+    /// Recipient token account disagrees with the transfer.
+    InvalidRecipient,
+
+    #[msg("InvalidMint")]
+    /// Mint disagrees with the transfer.
+    InvalidMint,
+
+    #[msg("WrappedAsNative")]
+    /// Wrapped mint cannot be transferred as a native token.
+    WrappedAsNative,
+
+    #[msg("InvalidChain")]
+    /// Transfer is not meant for this chain.
+    InvalidChain,
+
+    #[msg("AlreadyInitialized")]
+    /// Account was already created (e.g. the transfer was already redeemed).
+    AlreadyInitialized,
+}
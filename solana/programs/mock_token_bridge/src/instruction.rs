@@ -0,0 +1,249 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{self, instruction::Instruction},
+};
+use anchor_spl::token;
+use wormhole_anchor_sdk::token_bridge;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+/// Mock Token Bridge instructions. Variants share the same order (and
+/// encoding, where implemented) as [`token_bridge::Instruction`], so the SDK's
+/// CPI helpers can call this program.
+pub enum MockInstruction {
+    /// Create the config, which stores the Core Bridge program ID.
+    Initialize {
+        wormhole_program: Pubkey,
+    },
+    AttestToken,     // unsupported
+    CompleteNative,  // unsupported
+    CompleteWrapped, // unsupported
+    TransferWrapped, // unsupported
+    TransferNative,  // unsupported
+    /// Register a foreign Token Bridge without a governance VAA.
+    RegisterChain {
+        chain: u16,
+        emitter_address: [u8; 32],
+    },
+    /// Create a wrapped mint without an attestation VAA.
+    CreateWrapped {
+        token_chain: u16,
+        token_address: [u8; 32],
+        decimals: u8,
+    },
+    UpgradeContract, // unsupported
+    CompleteNativeWithPayload {},
+    CompleteWrappedWithPayload {},
+    TransferWrappedWithPayload {
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        recipient_chain: u16,
+        payload: Vec<u8>,
+        cpi_program_id: Option<Pubkey>,
+    },
+    TransferNativeWithPayload {
+        batch_id: u32,
+        amount: u64,
+        recipient_address: [u8; 32],
+        recipient_chain: u16,
+        payload: Vec<u8>,
+        cpi_program_id: Option<Pubkey>,
+    },
+}
+
+/// Derive the config account.
+pub fn config_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[token_bridge::Config::SEED_PREFIX], program_id).0
+}
+
+/// Derive the foreign endpoint account of a registered Token Bridge.
+pub fn endpoint_address(program_id: &Pubkey, chain: u16, emitter_address: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[&chain.to_be_bytes(), emitter_address], program_id).0
+}
+
+/// Derive the wrapped mint of a token native to a foreign chain.
+pub fn wrapped_mint_address(
+    program_id: &Pubkey,
+    token_chain: u16,
+    token_address: &[u8; 32],
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &token_chain.to_be_bytes(),
+            token_address,
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Derive the wrapped metadata account of a wrapped mint.
+pub fn wrapped_meta_address(program_id: &Pubkey, wrapped_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            token_bridge::WrappedMeta::SEED_PREFIX,
+            wrapped_mint.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Derive the custody token account of a native mint.
+pub fn custody_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[mint.as_ref()], program_id).0
+}
+
+/// Derive the claim account of a transfer.
+pub fn claim_address(
+    program_id: &Pubkey,
+    emitter_chain: u16,
+    emitter_address: &[u8; 32],
+    sequence: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            emitter_address,
+            &emitter_chain.to_be_bytes(),
+            &sequence.to_be_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Build an instruction to initialize the mock Token Bridge.
+pub fn initialize(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    wormhole_program: &Pubkey,
+) -> Result<Instruction> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(config_address(program_id), false),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ],
+        data: MockInstruction::Initialize {
+            wormhole_program: *wormhole_program,
+        }
+        .try_to_vec()?,
+    })
+}
+
+/// Build an instruction to register a foreign Token Bridge.
+pub fn register_chain(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    chain: u16,
+    emitter_address: [u8; 32],
+) -> Result<Instruction> {
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(config_address(program_id), false),
+            AccountMeta::new(endpoint_address(program_id, chain, &emitter_address), false),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ],
+        data: MockInstruction::RegisterChain {
+            chain,
+            emitter_address,
+        }
+        .try_to_vec()?,
+    })
+}
+
+/// Build an instruction to create a wrapped mint (and its metadata) for a
+/// token native to a foreign chain.
+pub fn create_wrapped(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    token_chain: u16,
+    token_address: [u8; 32],
+    decimals: u8,
+) -> Result<Instruction> {
+    let wrapped_mint = wrapped_mint_address(program_id, token_chain, &token_address);
+    let (mint_authority, _) =
+        Pubkey::find_program_address(&[token_bridge::SEED_PREFIX_MINT_AUTHORITY], program_id);
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(config_address(program_id), false),
+            AccountMeta::new(wrapped_mint, false),
+            AccountMeta::new(wrapped_meta_address(program_id, &wrapped_mint), false),
+            AccountMeta::new_readonly(mint_authority, false),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+            AccountMeta::new_readonly(token::ID, false),
+        ],
+        data: MockInstruction::CreateWrapped {
+            token_chain,
+            token_address,
+            decimals,
+        }
+        .try_to_vec()?,
+    })
+}
+
+#[test]
+fn sdk_instruction_compatibility() {
+    let cpi_program_id = Pubkey::new_unique();
+    let encoded = token_bridge::Instruction::TransferNativeWithPayload {
+        batch_id: 0,
+        amount: 420,
+        recipient_address: [1; 32],
+        recipient_chain: 2,
+        payload: b"All your base are belong to us.".to_vec(),
+        cpi_program_id: Some(cpi_program_id),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    match MockInstruction::try_from_slice(&encoded).unwrap() {
+        MockInstruction::TransferNativeWithPayload {
+            amount,
+            recipient_chain,
+            payload,
+            cpi_program_id: decoded_cpi_program_id,
+            ..
+        } => {
+            assert_eq!(amount, 420);
+            assert_eq!(recipient_chain, 2);
+            assert_eq!(payload, b"All your base are belong to us.");
+            assert_eq!(decoded_cpi_program_id, Some(cpi_program_id));
+        }
+        _ => panic!("Expected TransferNativeWithPayload"),
+    }
+
+    let encoded = token_bridge::Instruction::CompleteWrappedWithPayload {}
+        .try_to_vec()
+        .unwrap();
+    assert!(matches!(
+        MockInstruction::try_from_slice(&encoded).unwrap(),
+        MockInstruction::CompleteWrappedWithPayload {}
+    ));
+}
+
+#[test]
+fn sdk_pda_compatibility() {
+    let program_id = token_bridge::program::ID;
+    let token_address = [69; 32];
+
+    let wrapped_mint = wrapped_mint_address(&program_id, 2, &token_address);
+    assert_eq!(
+        wrapped_mint,
+        token_bridge::WrappedMint::find_address(2, &token_address).0
+    );
+    assert_eq!(
+        wrapped_meta_address(&program_id, &wrapped_mint),
+        token_bridge::WrappedMeta::find_address(&wrapped_mint).0
+    );
+    assert_eq!(
+        endpoint_address(&program_id, 2, &token_address),
+        token_bridge::EndpointRegistration::find_address(2, &token_address).0
+    );
+}
@@ -0,0 +1,43 @@
+//! # Mock Token Bridge
+//!
+//! Minimal stand-in for the Wormhole Token Bridge program, meant to be
+//! deployed at the Token Bridge program ID (see
+//! [`token_bridge::program::ID`](wormhole_anchor_sdk::token_bridge::program::ID))
+//! alongside the mock Core Bridge in local integration tests (e.g.
+//! `solana-program-test` or LiteSVM).
+//!
+//! Accounts written by this program use the same layout and PDAs as the real
+//! Token Bridge ([`Config`], [`WrappedMint`], [`WrappedMeta`],
+//! [`EndpointRegistration`], custody, claim and signer PDAs). Transfers and
+//! redemptions of native and wrapped tokens with payload are implemented with
+//! the same accounts the SDK's CPI helpers pass in.
+//!
+//! Governance is replaced by permissionless [`register_chain`] and
+//! [`create_wrapped`] instructions. Never deploy this program outside of
+//! tests.
+//!
+//! [`Config`]: wormhole_anchor_sdk::token_bridge::Config
+//! [`WrappedMint`]: wormhole_anchor_sdk::token_bridge::WrappedMint
+//! [`WrappedMeta`]: wormhole_anchor_sdk::token_bridge::WrappedMeta
+//! [`EndpointRegistration`]: wormhole_anchor_sdk::token_bridge::EndpointRegistration
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+pub use error::*;
+pub use instruction::*;
+
+pub mod error;
+pub mod instruction;
+mod processor;
+
+#[cfg(not(feature = "no-entrypoint"))]
+anchor_lang::solana_program::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    processor::process(program_id, accounts, instruction_data).map_err(Into::into)
+}
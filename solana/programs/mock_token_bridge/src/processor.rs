@@ -0,0 +1,779 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{account_info::next_account_info, program_option::COption},
+    system_program,
+};
+use anchor_spl::token;
+use wormhole_anchor_sdk::{
+    token_bridge::{
+        self, checked_normalize_amount, Config, EndpointRegistration, RedeemerAuthority,
+        SenderAuthority, TransferHeader, TransferWithPayload, WrappedMeta,
+        MAX_WRAPPED_ASSET_DECIMALS,
+    },
+    wormhole::{self, PostedVaa, CHAIN_ID_SOLANA},
+};
+
+use crate::{error::MockTokenBridgeError, instruction::*};
+
+/// Arguments shared by outbound transfers with payload.
+struct TransferArgs {
+    batch_id: u32,
+    amount: u64,
+    recipient_address: [u8; 32],
+    recipient_chain: u16,
+    payload: Vec<u8>,
+    cpi_program_id: Option<Pubkey>,
+}
+
+pub fn process<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    mut data: &[u8],
+) -> Result<()> {
+    match MockInstruction::deserialize(&mut data)? {
+        MockInstruction::Initialize { wormhole_program } => {
+            initialize(program_id, accounts, wormhole_program)
+        }
+        MockInstruction::RegisterChain {
+            chain,
+            emitter_address,
+        } => register_chain(program_id, accounts, chain, emitter_address),
+        MockInstruction::CreateWrapped {
+            token_chain,
+            token_address,
+            decimals,
+        } => create_wrapped(program_id, accounts, token_chain, token_address, decimals),
+        MockInstruction::CompleteNativeWithPayload {} => {
+            complete_native_with_payload(program_id, accounts)
+        }
+        MockInstruction::CompleteWrappedWithPayload {} => {
+            complete_wrapped_with_payload(program_id, accounts)
+        }
+        MockInstruction::TransferWrappedWithPayload {
+            batch_id,
+            amount,
+            recipient_address,
+            recipient_chain,
+            payload,
+            cpi_program_id,
+        } => transfer_wrapped_with_payload(
+            program_id,
+            accounts,
+            TransferArgs {
+                batch_id,
+                amount,
+                recipient_address,
+                recipient_chain,
+                payload,
+                cpi_program_id,
+            },
+        ),
+        MockInstruction::TransferNativeWithPayload {
+            batch_id,
+            amount,
+            recipient_address,
+            recipient_chain,
+            payload,
+            cpi_program_id,
+        } => transfer_native_with_payload(
+            program_id,
+            accounts,
+            TransferArgs {
+                batch_id,
+                amount,
+                recipient_address,
+                recipient_chain,
+                payload,
+                cpi_program_id,
+            },
+        ),
+        _ => err!(MockTokenBridgeError::UnsupportedInstruction),
+    }
+}
+
+fn initialize<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    wormhole_program: Pubkey,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+
+    let bump = find_bump(config, &[Config::SEED_PREFIX], program_id)?;
+    create_program_account(
+        program_id,
+        payer,
+        config,
+        system_program,
+        &Config {
+            wormhole_bridge: wormhole_program,
+        }
+        .try_to_vec()?,
+        &[Config::SEED_PREFIX, &[bump]],
+    )
+}
+
+fn register_chain<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    chain: u16,
+    emitter_address: [u8; 32],
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let endpoint = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+
+    load_config(program_id, config)?;
+    require!(chain != CHAIN_ID_SOLANA, MockTokenBridgeError::InvalidChain);
+
+    let chain_bytes = chain.to_be_bytes();
+    let bump = find_bump(endpoint, &[&chain_bytes, &emitter_address], program_id)?;
+    create_program_account(
+        program_id,
+        payer,
+        endpoint,
+        system_program,
+        &EndpointRegistration {
+            emitter_chain: chain,
+            emitter_address,
+        }
+        .try_to_vec()?,
+        &[&chain_bytes, &emitter_address, &[bump]],
+    )
+}
+
+fn create_wrapped<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    token_chain: u16,
+    token_address: [u8; 32],
+    decimals: u8,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let wrapped_mint = next_account_info(accounts)?;
+    let wrapped_meta = next_account_info(accounts)?;
+    let mint_authority = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+
+    load_config(program_id, config)?;
+    require!(
+        token_chain != CHAIN_ID_SOLANA,
+        MockTokenBridgeError::InvalidChain
+    );
+    find_bump(
+        mint_authority,
+        &[token_bridge::SEED_PREFIX_MINT_AUTHORITY],
+        program_id,
+    )?;
+
+    // Wrapped mints have at most 8 decimals.
+    let chain_bytes = token_chain.to_be_bytes();
+    let bump = find_bump(
+        wrapped_mint,
+        &[
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &chain_bytes,
+            &token_address,
+        ],
+        program_id,
+    )?;
+    create_account(
+        payer,
+        wrapped_mint,
+        system_program,
+        &token::ID,
+        token::Mint::LEN,
+        &[
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &chain_bytes,
+            &token_address,
+            &[bump],
+        ],
+    )?;
+    token::initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            token::InitializeMint2 {
+                mint: wrapped_mint.clone(),
+            },
+        ),
+        decimals.min(MAX_WRAPPED_ASSET_DECIMALS),
+        mint_authority.key,
+        None,
+    )?;
+
+    let bump = find_bump(
+        wrapped_meta,
+        &[WrappedMeta::SEED_PREFIX, wrapped_mint.key.as_ref()],
+        program_id,
+    )?;
+    create_program_account(
+        program_id,
+        payer,
+        wrapped_meta,
+        system_program,
+        &WrappedMeta {
+            chain: token_chain,
+            token_address,
+            original_decimals: decimals,
+        }
+        .try_to_vec()?,
+        &[WrappedMeta::SEED_PREFIX, wrapped_mint.key.as_ref(), &[bump]],
+    )
+}
+
+fn transfer_native_with_payload<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    args: TransferArgs,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let from = next_account_info(accounts)?;
+    let mint = next_account_info(accounts)?;
+    let custody = next_account_info(accounts)?;
+    let authority_signer = next_account_info(accounts)?;
+    let custody_signer = next_account_info(accounts)?;
+    let wormhole_bridge = next_account_info(accounts)?;
+    let wormhole_message = next_account_info(accounts)?;
+    let wormhole_emitter = next_account_info(accounts)?;
+    let wormhole_sequence = next_account_info(accounts)?;
+    let wormhole_fee_collector = next_account_info(accounts)?;
+    let clock = next_account_info(accounts)?;
+    let sender = next_account_info(accounts)?;
+    let rent = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let wormhole_program = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+
+    let config_data = load_config(program_id, config)?;
+    let from_address = verify_sender(sender, args.cpi_program_id)?;
+
+    // Mints created by this program can only be transferred as wrapped.
+    let mint_data = load_mint(mint)?;
+    let (mint_signer, _) =
+        Pubkey::find_program_address(&[token_bridge::SEED_PREFIX_MINT_AUTHORITY], program_id);
+    require!(
+        mint_data.mint_authority != COption::Some(mint_signer),
+        MockTokenBridgeError::WrappedAsNative
+    );
+
+    find_bump(
+        custody_signer,
+        &[token_bridge::SEED_PREFIX_CUSTODY_SIGNER],
+        program_id,
+    )?;
+    let custody_bump = find_bump(custody, &[mint.key.as_ref()], program_id)?;
+    if custody.data_is_empty() {
+        create_account(
+            payer,
+            custody,
+            system_program,
+            &token::ID,
+            token::TokenAccount::LEN,
+            &[mint.key.as_ref(), &[custody_bump]],
+        )?;
+        token::initialize_account3(CpiContext::new(
+            token_program.clone(),
+            token::InitializeAccount3 {
+                account: custody.clone(),
+                mint: mint.clone(),
+                authority: custody_signer.clone(),
+            },
+        ))?;
+    }
+
+    // Only the truncated amount is taken into custody.
+    let normalized = checked_normalize_amount(args.amount, mint_data.decimals)?;
+    let authority_bump = find_bump(
+        authority_signer,
+        &[token_bridge::SEED_PREFIX_AUTHORITY_SIGNER],
+        program_id,
+    )?;
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::Transfer {
+                from: from.clone(),
+                to: custody.clone(),
+                authority: authority_signer.clone(),
+            },
+            &[&[
+                token_bridge::SEED_PREFIX_AUTHORITY_SIGNER,
+                &[authority_bump],
+            ]],
+        ),
+        normalized.to_native(mint_data.decimals)?,
+    )?;
+
+    post_transfer(
+        program_id,
+        &config_data,
+        wormhole_program,
+        wormhole::PostMessage {
+            config: wormhole_bridge.clone(),
+            message: wormhole_message.clone(),
+            emitter: wormhole_emitter.clone(),
+            sequence: wormhole_sequence.clone(),
+            payer: payer.clone(),
+            fee_collector: wormhole_fee_collector.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+        },
+        TransferHeader {
+            amount: normalized.get(),
+            token_address: mint.key.to_bytes(),
+            token_chain: CHAIN_ID_SOLANA,
+            to_address: args.recipient_address,
+            to_chain: args.recipient_chain,
+            from_address,
+        },
+        &args,
+    )
+}
+
+fn transfer_wrapped_with_payload<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    args: TransferArgs,
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let from = next_account_info(accounts)?;
+    let _from_owner = next_account_info(accounts)?;
+    let wrapped_mint = next_account_info(accounts)?;
+    let wrapped_meta = next_account_info(accounts)?;
+    let authority_signer = next_account_info(accounts)?;
+    let wormhole_bridge = next_account_info(accounts)?;
+    let wormhole_message = next_account_info(accounts)?;
+    let wormhole_emitter = next_account_info(accounts)?;
+    let wormhole_sequence = next_account_info(accounts)?;
+    let wormhole_fee_collector = next_account_info(accounts)?;
+    let clock = next_account_info(accounts)?;
+    let sender = next_account_info(accounts)?;
+    let rent = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let wormhole_program = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+
+    let config_data = load_config(program_id, config)?;
+    let from_address = verify_sender(sender, args.cpi_program_id)?;
+    let meta = load_wrapped_meta(program_id, wrapped_mint, wrapped_meta)?;
+
+    // Wrapped mints have at most 8 decimals, so the amount is already
+    // normalized.
+    let authority_bump = find_bump(
+        authority_signer,
+        &[token_bridge::SEED_PREFIX_AUTHORITY_SIGNER],
+        program_id,
+    )?;
+    token::burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::Burn {
+                mint: wrapped_mint.clone(),
+                from: from.clone(),
+                authority: authority_signer.clone(),
+            },
+            &[&[
+                token_bridge::SEED_PREFIX_AUTHORITY_SIGNER,
+                &[authority_bump],
+            ]],
+        ),
+        args.amount,
+    )?;
+
+    post_transfer(
+        program_id,
+        &config_data,
+        wormhole_program,
+        wormhole::PostMessage {
+            config: wormhole_bridge.clone(),
+            message: wormhole_message.clone(),
+            emitter: wormhole_emitter.clone(),
+            sequence: wormhole_sequence.clone(),
+            payer: payer.clone(),
+            fee_collector: wormhole_fee_collector.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+        },
+        TransferHeader {
+            amount: args.amount,
+            token_address: meta.token_address,
+            token_chain: meta.chain,
+            to_address: args.recipient_address,
+            to_chain: args.recipient_chain,
+            from_address,
+        },
+        &args,
+    )
+}
+
+fn complete_native_with_payload<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let vaa = next_account_info(accounts)?;
+    let claim = next_account_info(accounts)?;
+    let foreign_endpoint = next_account_info(accounts)?;
+    let to = next_account_info(accounts)?;
+    let redeemer = next_account_info(accounts)?;
+    let _to_fees = next_account_info(accounts)?;
+    let custody = next_account_info(accounts)?;
+    let mint = next_account_info(accounts)?;
+    let custody_signer = next_account_info(accounts)?;
+    let _rent = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let _wormhole_program = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+
+    let config_data = load_config(program_id, config)?;
+    let vaa = load_transfer(program_id, &config_data, vaa, foreign_endpoint)?;
+    let transfer = vaa.data();
+
+    require!(
+        transfer.token_chain() == CHAIN_ID_SOLANA,
+        MockTokenBridgeError::InvalidMint
+    );
+    require!(
+        mint.key.to_bytes() == *transfer.token_address(),
+        MockTokenBridgeError::InvalidMint
+    );
+    verify_redeemer(redeemer, to, mint, &transfer.to())?;
+    claim_transfer(program_id, payer, claim, system_program, &vaa)?;
+
+    let custody_bump = find_bump(
+        custody_signer,
+        &[token_bridge::SEED_PREFIX_CUSTODY_SIGNER],
+        program_id,
+    )?;
+    find_bump(custody, &[mint.key.as_ref()], program_id)?;
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::Transfer {
+                from: custody.clone(),
+                to: to.clone(),
+                authority: custody_signer.clone(),
+            },
+            &[&[token_bridge::SEED_PREFIX_CUSTODY_SIGNER, &[custody_bump]]],
+        ),
+        transfer
+            .normalized_amount()
+            .to_native(load_mint(mint)?.decimals)?,
+    )
+}
+
+fn complete_wrapped_with_payload<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let config = next_account_info(accounts)?;
+    let vaa = next_account_info(accounts)?;
+    let claim = next_account_info(accounts)?;
+    let foreign_endpoint = next_account_info(accounts)?;
+    let to = next_account_info(accounts)?;
+    let redeemer = next_account_info(accounts)?;
+    let _to_fees = next_account_info(accounts)?;
+    let wrapped_mint = next_account_info(accounts)?;
+    let wrapped_meta = next_account_info(accounts)?;
+    let mint_authority = next_account_info(accounts)?;
+    let _rent = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let _wormhole_program = next_account_info(accounts)?;
+    let token_program = next_account_info(accounts)?;
+
+    let config_data = load_config(program_id, config)?;
+    let vaa = load_transfer(program_id, &config_data, vaa, foreign_endpoint)?;
+    let transfer = vaa.data();
+
+    let meta = load_wrapped_meta(program_id, wrapped_mint, wrapped_meta)?;
+    require!(
+        meta.chain == transfer.token_chain() && meta.token_address == *transfer.token_address(),
+        MockTokenBridgeError::InvalidMint
+    );
+    verify_redeemer(redeemer, to, wrapped_mint, &transfer.to())?;
+    claim_transfer(program_id, payer, claim, system_program, &vaa)?;
+
+    // Wrapped mints have at most 8 decimals, so the encoded amount is minted
+    // as is.
+    let mint_authority_bump = find_bump(
+        mint_authority,
+        &[token_bridge::SEED_PREFIX_MINT_AUTHORITY],
+        program_id,
+    )?;
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::MintTo {
+                mint: wrapped_mint.clone(),
+                to: to.clone(),
+                authority: mint_authority.clone(),
+            },
+            &[&[
+                token_bridge::SEED_PREFIX_MINT_AUTHORITY,
+                &[mint_authority_bump],
+            ]],
+        ),
+        transfer.amount(),
+    )
+}
+
+/// Post the Token Bridge transfer with payload via the Core Bridge, where
+/// this program's emitter signs.
+fn post_transfer<'info>(
+    program_id: &Pubkey,
+    config: &Config,
+    wormhole_program: &AccountInfo<'info>,
+    accounts: wormhole::PostMessage<'info>,
+    header: TransferHeader,
+    args: &TransferArgs,
+) -> Result<()> {
+    require_keys_eq!(
+        wormhole_program.key(),
+        config.wormhole_bridge,
+        MockTokenBridgeError::InvalidWormholeProgram
+    );
+    let emitter_bump = find_bump(
+        &accounts.emitter,
+        &[token_bridge::SEED_PREFIX_EMITTER],
+        program_id,
+    )?;
+
+    let mut payload = header.try_to_vec()?;
+    payload.extend_from_slice(&args.payload);
+
+    wormhole::post_message(
+        CpiContext::new_with_signer(
+            wormhole_program.clone(),
+            accounts,
+            &[&[token_bridge::SEED_PREFIX_EMITTER, &[emitter_bump]]],
+        ),
+        args.batch_id,
+        payload,
+        wormhole::Finality::Finalized,
+    )
+}
+
+/// Verify the sender and determine the transfer's from address. When a CPI
+/// program ID is specified, the sender must be its sender PDA.
+fn verify_sender(sender: &AccountInfo, cpi_program_id: Option<Pubkey>) -> Result<[u8; 32]> {
+    require!(sender.is_signer, MockTokenBridgeError::InvalidSender);
+
+    match cpi_program_id {
+        Some(cpi_program_id) => {
+            require_keys_eq!(
+                sender.key(),
+                SenderAuthority::find_address(&cpi_program_id).0,
+                MockTokenBridgeError::InvalidSender
+            );
+            Ok(cpi_program_id.to_bytes())
+        }
+        None => Ok(sender.key.to_bytes()),
+    }
+}
+
+/// Verify the redeemer is either the transfer recipient or the recipient
+/// program's redeemer PDA, and that it owns the destination token account.
+fn verify_redeemer(
+    redeemer: &AccountInfo,
+    to: &AccountInfo,
+    mint: &AccountInfo,
+    recipient: &Pubkey,
+) -> Result<()> {
+    require!(redeemer.is_signer, MockTokenBridgeError::InvalidRedeemer);
+    require!(
+        redeemer.key == recipient || *redeemer.key == RedeemerAuthority::find_address(recipient).0,
+        MockTokenBridgeError::InvalidRedeemer
+    );
+
+    require_keys_eq!(*to.owner, token::ID, MockTokenBridgeError::InvalidRecipient);
+    let to_data = token::TokenAccount::try_deserialize(&mut &to.data.borrow()[..])?;
+    require!(
+        to_data.mint == mint.key() && to_data.owner == redeemer.key(),
+        MockTokenBridgeError::InvalidRecipient
+    );
+
+    Ok(())
+}
+
+/// Load the posted transfer, which must be owned by the Core Bridge and be
+/// emitted by a registered foreign Token Bridge.
+fn load_transfer(
+    program_id: &Pubkey,
+    config: &Config,
+    vaa: &AccountInfo,
+    foreign_endpoint: &AccountInfo,
+) -> Result<PostedVaa<TransferWithPayload>> {
+    require_keys_eq!(
+        *vaa.owner,
+        config.wormhole_bridge,
+        MockTokenBridgeError::InvalidWormholeProgram
+    );
+    let vaa = PostedVaa::<TransferWithPayload>::try_deserialize(&mut &vaa.data.borrow()[..])?;
+
+    require_keys_eq!(
+        *foreign_endpoint.owner,
+        *program_id,
+        MockTokenBridgeError::InvalidForeignEndpoint
+    );
+    require_keys_eq!(
+        foreign_endpoint.key(),
+        endpoint_address(program_id, vaa.emitter_chain(), vaa.emitter_address()),
+        MockTokenBridgeError::InvalidForeignEndpoint
+    );
+    require!(
+        vaa.data().to_chain() == CHAIN_ID_SOLANA,
+        MockTokenBridgeError::InvalidChain
+    );
+
+    Ok(vaa)
+}
+
+/// Create the claim account, which fails if the transfer was already
+/// redeemed.
+fn claim_transfer<'info>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'info>,
+    claim: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    vaa: &PostedVaa<TransferWithPayload>,
+) -> Result<()> {
+    let chain_bytes = vaa.emitter_chain().to_be_bytes();
+    let sequence_bytes = vaa.sequence().to_be_bytes();
+    let bump = find_bump(
+        claim,
+        &[vaa.emitter_address(), &chain_bytes, &sequence_bytes],
+        program_id,
+    )?;
+    require!(
+        claim.data_is_empty(),
+        MockTokenBridgeError::AlreadyInitialized
+    );
+    create_program_account(
+        program_id,
+        payer,
+        claim,
+        system_program,
+        &[1],
+        &[
+            vaa.emitter_address(),
+            &chain_bytes,
+            &sequence_bytes,
+            &[bump],
+        ],
+    )
+}
+
+fn load_config(program_id: &Pubkey, config: &AccountInfo) -> Result<Config> {
+    require_keys_eq!(
+        config.key(),
+        config_address(program_id),
+        MockTokenBridgeError::InvalidConfig
+    );
+    require_keys_eq!(
+        *config.owner,
+        *program_id,
+        MockTokenBridgeError::InvalidConfig
+    );
+    Config::try_deserialize(&mut &config.data.borrow()[..])
+}
+
+fn load_mint(mint: &AccountInfo) -> Result<token::Mint> {
+    require_keys_eq!(*mint.owner, token::ID, MockTokenBridgeError::InvalidMint);
+    token::Mint::try_deserialize(&mut &mint.data.borrow()[..])
+}
+
+fn load_wrapped_meta(
+    program_id: &Pubkey,
+    wrapped_mint: &AccountInfo,
+    wrapped_meta: &AccountInfo,
+) -> Result<WrappedMeta> {
+    require_keys_eq!(
+        wrapped_meta.key(),
+        wrapped_meta_address(program_id, wrapped_mint.key),
+        MockTokenBridgeError::InvalidMint
+    );
+    require_keys_eq!(
+        *wrapped_meta.owner,
+        *program_id,
+        MockTokenBridgeError::InvalidMint
+    );
+    let meta = WrappedMeta::try_deserialize(&mut &wrapped_meta.data.borrow()[..])?;
+    require_keys_eq!(
+        wrapped_mint.key(),
+        wrapped_mint_address(program_id, meta.chain, &meta.token_address),
+        MockTokenBridgeError::InvalidMint
+    );
+
+    Ok(meta)
+}
+
+/// Verify the account is the PDA derived from the seeds and return its bump.
+fn find_bump(account: &AccountInfo, seeds: &[&[u8]], program_id: &Pubkey) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, program_id);
+    require_keys_eq!(account.key(), expected, MockTokenBridgeError::InvalidPda);
+
+    Ok(bump)
+}
+
+fn create_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: &Pubkey,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::CreateAccount {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        owner,
+    )
+}
+
+/// Create an account owned by this program and write its data.
+fn create_program_account<'info>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    data: &[u8],
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    create_account(
+        payer,
+        account,
+        system_program,
+        program_id,
+        data.len(),
+        signer_seeds,
+    )?;
+    account.data.borrow_mut().copy_from_slice(data);
+
+    Ok(())
+}
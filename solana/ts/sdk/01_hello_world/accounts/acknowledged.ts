@@ -0,0 +1,52 @@
+import { ChainId } from "@certusone/wormhole-sdk";
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { Connection, PublicKeyInitData } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+
+export function deriveAcknowledgedKey(
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  sequence: bigint
+) {
+  return deriveAddress(
+    [
+      Buffer.from("acknowledged"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      emitterAddress,
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigUInt64LE(sequence);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
+export interface Acknowledged {
+  sequence: bigint;
+  vaaHash: Buffer;
+  wormholeMessageHash: Buffer;
+}
+
+export async function getAcknowledgedData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  sequence: bigint
+): Promise<Acknowledged> {
+  const acknowledged = await createHelloWorldProgramInterface(connection, programId)
+    .account.acknowledged.fetch(deriveAcknowledgedKey(programId, chain, emitterAddress, sequence));
+
+  return {
+    sequence: BigInt(acknowledged.sequence.toString()),
+    vaaHash: Buffer.from(acknowledged.vaaHash),
+    wormholeMessageHash: Buffer.from(acknowledged.wormholeMessageHash),
+  };
+}
@@ -0,0 +1,31 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+
+export function deriveAllowedSenderKey(programId: PublicKeyInitData, sender: PublicKeyInitData) {
+  return deriveAddress([Buffer.from("allowed_sender"), new PublicKey(sender).toBuffer()], programId);
+}
+
+// A program that sends messages via CPI signs with this PDA.
+export function deriveProgramSenderKey(senderProgramId: PublicKeyInitData) {
+  return deriveAddress([Buffer.from("sender")], senderProgramId);
+}
+
+export interface AllowedSender {
+  sender: PublicKey;
+  program: PublicKey | null;
+}
+
+export async function getAllowedSenderData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  sender: PublicKeyInitData
+): Promise<AllowedSender> {
+  const allowedSender = await createHelloWorldProgramInterface(connection, programId)
+    .account.allowedSender.fetch(deriveAllowedSenderKey(programId, sender));
+
+  return {
+    sender: allowedSender.sender,
+    program: allowedSender.program,
+  };
+}
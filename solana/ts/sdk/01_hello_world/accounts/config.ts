@@ -12,9 +12,27 @@ export interface WormholeAddresses {
   sequence: PublicKey;
 }
 
+export interface RateLimitParams {
+  capacity: bigint;
+  secondsPerToken: bigint;
+}
+
 export interface ConfigData {
   owner: PublicKey;
   wormhole: WormholeAddresses;
+  pendingOwner: PublicKey | null;
+  pauseOutbound: boolean;
+  pauseInbound: boolean;
+  batchId: number;
+  finality: number;
+  finalizedFee: bigint;
+  globalRateLimit: RateLimitParams;
+  senderRateLimit: RateLimitParams;
+  appFee: bigint;
+  feeRecipient: PublicKey;
+  allowlistEnabled: boolean;
+  governanceChain: number;
+  governanceEmitter: Buffer;
 }
 
 export async function getConfigData(
@@ -27,5 +45,24 @@ export async function getConfigData(
   return {
     owner: data.owner,
     wormhole: data.wormhole,
+    pendingOwner: data.pendingOwner,
+    pauseOutbound: data.pauseOutbound,
+    pauseInbound: data.pauseInbound,
+    batchId: data.batchId,
+    finality: data.finality,
+    finalizedFee: BigInt(data.finalizedFee.toString()),
+    globalRateLimit: {
+      capacity: BigInt(data.globalRateLimit.capacity.toString()),
+      secondsPerToken: BigInt(data.globalRateLimit.secondsPerToken.toString()),
+    },
+    senderRateLimit: {
+      capacity: BigInt(data.senderRateLimit.capacity.toString()),
+      secondsPerToken: BigInt(data.senderRateLimit.secondsPerToken.toString()),
+    },
+    appFee: BigInt(data.appFee.toString()),
+    feeRecipient: data.feeRecipient,
+    allowlistEnabled: data.allowlistEnabled,
+    governanceChain: data.governanceChain,
+    governanceEmitter: Buffer.from(data.governanceEmitter),
   };
 }
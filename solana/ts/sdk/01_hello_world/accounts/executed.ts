@@ -0,0 +1,48 @@
+import { ChainId } from "@certusone/wormhole-sdk";
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { Connection, PublicKeyInitData } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+
+export function deriveExecutedKey(
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  sequence: bigint
+) {
+  return deriveAddress(
+    [
+      Buffer.from("executed"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      emitterAddress,
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigInt64LE(sequence);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
+export interface Executed {
+  wormholeMessageHash: Buffer;
+}
+
+export async function getExecutedData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  sequence: bigint
+): Promise<Executed> {
+  const executed = await createHelloWorldProgramInterface(connection, programId)
+    .account.executed.fetch(deriveExecutedKey(programId, chain, emitterAddress, sequence));
+
+  return {
+    wormholeMessageHash: Buffer.from(executed.wormholeMessageHash),
+  };
+}
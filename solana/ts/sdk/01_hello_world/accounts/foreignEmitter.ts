@@ -5,8 +5,25 @@ import { createHelloWorldProgramInterface } from "../program";
 
 export function deriveForeignEmitterKey(
   programId: PublicKeyInitData,
-  chain: ChainId
+  chain: ChainId,
+  address: Buffer
 ) {
+  return deriveAddress(
+    [
+      Buffer.from("foreign_emitter"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      address,
+    ],
+    programId
+  );
+}
+
+// Foreign emitter account registered when there was one emitter per chain.
+export function deriveLegacyForeignEmitterKey(programId: PublicKeyInitData, chain: ChainId) {
   return deriveAddress(
     [
       Buffer.from("foreign_emitter"),
@@ -23,18 +40,38 @@ export function deriveForeignEmitterKey(
 export interface ForeignEmitter {
   chain: ChainId;
   address: Buffer;
+  enabled: boolean;
+  ordered: boolean;
+  nextExpectedSequence: bigint;
+  sendAcks: boolean;
+  maxMessageAge: number;
+  maxFutureDrift: number | null;
 }
 
 export async function getForeignEmitterData(
   connection: Connection,
   programId: PublicKeyInitData,
-  chain: ChainId
+  chain: ChainId,
+  address: Buffer
 ): Promise<ForeignEmitter> {
-  const { address } = await createHelloWorldProgramInterface(connection, programId)
-    .account.foreignEmitter.fetch(deriveForeignEmitterKey(programId, chain));
+  const {
+    enabled,
+    ordered,
+    nextExpectedSequence,
+    sendAcks,
+    maxMessageAge,
+    maxFutureDrift,
+  } = await createHelloWorldProgramInterface(connection, programId)
+    .account.foreignEmitter.fetch(deriveForeignEmitterKey(programId, chain, address));
 
   return {
     chain,
-    address: Buffer.from(address),
+    address,
+    enabled,
+    ordered,
+    nextExpectedSequence: BigInt(nextExpectedSequence.toString()),
+    sendAcks,
+    maxMessageAge,
+    maxFutureDrift,
   };
 }
@@ -1,4 +1,10 @@
+export * from "./acknowledged";
+export * from "./allowedSender";
 export * from "./config";
+export * from "./executed";
 export * from "./foreignEmitter";
+export * from "./rateLimit";
+export * from "./reassembly";
 export * from "./received";
+export * from "./treasury";
 export * from "./wormhole";
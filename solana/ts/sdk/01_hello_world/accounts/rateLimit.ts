@@ -0,0 +1,35 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+
+// Derives the global token bucket, or the sender's if a sender is specified.
+export function deriveRateLimitKey(
+  programId: PublicKeyInitData,
+  sender?: PublicKeyInitData
+) {
+  return deriveAddress(
+    sender === undefined
+      ? [Buffer.from("rate_limit")]
+      : [Buffer.from("rate_limit"), new PublicKey(sender).toBuffer()],
+    programId
+  );
+}
+
+export interface RateLimit {
+  tokens: bigint;
+  lastRefill: bigint;
+}
+
+export async function getRateLimitData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  sender?: PublicKeyInitData
+): Promise<RateLimit> {
+  const { tokens, lastRefill } = await createHelloWorldProgramInterface(connection, programId)
+    .account.rateLimit.fetch(deriveRateLimitKey(programId, sender));
+
+  return {
+    tokens: BigInt(tokens.toString()),
+    lastRefill: BigInt(lastRefill.toString()),
+  };
+}
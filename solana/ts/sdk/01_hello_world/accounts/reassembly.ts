@@ -0,0 +1,66 @@
+import { ChainId } from "@certusone/wormhole-sdk";
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+
+export function deriveReassemblyKey(
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  messageId: bigint
+) {
+  return deriveAddress(
+    [
+      Buffer.from("reassembly"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      emitterAddress,
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigUInt64LE(messageId);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
+export interface Reassembly {
+  payer: PublicKey;
+  messageId: bigint;
+  totalChunks: number;
+  receivedChunks: number;
+  hash: Buffer;
+  complete: boolean;
+  failed: boolean;
+  closed: boolean;
+  lastUpdated: bigint;
+  message: Buffer;
+}
+
+export async function getReassemblyData(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  messageId: bigint
+): Promise<Reassembly> {
+  const reassembly = await createHelloWorldProgramInterface(connection, programId)
+    .account.reassembly.fetch(deriveReassemblyKey(programId, chain, emitterAddress, messageId));
+
+  return {
+    payer: reassembly.payer,
+    messageId: BigInt(reassembly.messageId.toString()),
+    totalChunks: reassembly.totalChunks,
+    receivedChunks: reassembly.receivedChunks,
+    hash: Buffer.from(reassembly.hash),
+    complete: reassembly.complete,
+    failed: reassembly.failed,
+    closed: reassembly.closed,
+    lastUpdated: BigInt(reassembly.lastUpdated.toString()),
+    message: reassembly.message as Buffer,
+  };
+}
@@ -1,20 +1,50 @@
 import { ChainId } from "@certusone/wormhole-sdk";
 import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
-import { Connection, PublicKeyInitData } from "@solana/web3.js";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
 import { createHelloWorldProgramInterface } from "../program";
 
 export function deriveReceivedKey(
   programId: PublicKeyInitData,
   chain: ChainId,
+  emitterAddress: Buffer,
   sequence: bigint
 ) {
   return deriveAddress(
     [
       Buffer.from("received"),
       (() => {
-        const buf = Buffer.alloc(10);
-        buf.writeUInt16LE(chain, 0);
-        buf.writeBigInt64LE(sequence, 2);
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      emitterAddress,
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigInt64LE(sequence);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
+// Received account from when its seeds did not include the emitter address.
+export function deriveLegacyReceivedKey(
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  sequence: bigint
+) {
+  return deriveAddress(
+    [
+      Buffer.from("received"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigInt64LE(sequence);
         return buf;
       })(),
     ],
@@ -25,19 +55,22 @@ export function deriveReceivedKey(
 export interface Received {
   batchId: number;
   message: Buffer;
+  payer: PublicKey;
 }
 
 export async function getReceivedData(
   connection: Connection,
   programId: PublicKeyInitData,
   chain: ChainId,
+  emitterAddress: Buffer,
   sequence: bigint
 ): Promise<Received> {
   const received = await createHelloWorldProgramInterface(connection, programId)
-    .account.received.fetch(deriveReceivedKey(programId, chain, sequence));
+    .account.received.fetch(deriveReceivedKey(programId, chain, emitterAddress, sequence));
 
   return {
     batchId: received.batchId,
-    message: received.message as Buffer
+    message: received.message as Buffer,
+    payer: received.payer,
   };
 }
@@ -0,0 +1,6 @@
+import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { PublicKeyInitData } from "@solana/web3.js";
+
+export function deriveTreasuryKey(programId: PublicKeyInitData) {
+  return deriveAddress([Buffer.from("treasury")], programId);
+}
@@ -1,6 +1,7 @@
+import { ChainId } from "@certusone/wormhole-sdk";
 import { deriveAddress } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import { deriveWormholeEmitterKey } from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
-import { Connection, PublicKeyInitData } from "@solana/web3.js";
+import { Connection, PublicKey, PublicKeyInitData } from "@solana/web3.js";
 import { createHelloWorldProgramInterface } from "../program";
 
 export { deriveWormholeEmitterKey };
@@ -22,6 +23,50 @@ export function deriveWormholeMessageKey(
   );
 }
 
+export function deriveSentMessageKey(
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  nonce: bigint
+) {
+  return deriveAddress(
+    [
+      Buffer.from("sent"),
+      new PublicKey(payer).toBuffer(),
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigUInt64LE(nonce);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
+export function deriveAckMessageKey(
+  programId: PublicKeyInitData,
+  chain: ChainId,
+  emitterAddress: Buffer,
+  sequence: bigint
+) {
+  return deriveAddress(
+    [
+      Buffer.from("ack"),
+      (() => {
+        const buf = Buffer.alloc(2);
+        buf.writeUInt16LE(chain);
+        return buf;
+      })(),
+      emitterAddress,
+      (() => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigUInt64LE(sequence);
+        return buf;
+      })(),
+    ],
+    programId
+  );
+}
+
 export interface WormholeEmitterData {
   bump: number;
 }
@@ -0,0 +1,41 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionSignature } from "@solana/web3.js";
+import { utils } from "@coral-xyz/anchor";
+import { createHelloWorldProgramInterface } from "./program";
+
+// Prefix of the self-CPI instruction data written by `emit_cpi!`.
+const EVENT_IX_TAG = Buffer.from("e445a52e51cb9a1d", "hex");
+
+export interface HelloWorldEvent {
+  name: string;
+  data: any;
+}
+
+// Events are emitted with `emit_cpi!`, so they are found in the inner
+// instructions of a confirmed transaction instead of its logs.
+export async function getHelloWorldEvents(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  signature: TransactionSignature
+): Promise<HelloWorldEvent[]> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  if (tx === null || tx.meta === null || tx.meta.innerInstructions == undefined) {
+    return [];
+  }
+
+  const accountKeys = tx.transaction.message.getAccountKeys({
+    accountKeysFromLookups: tx.meta.loadedAddresses,
+  });
+  const helloWorldProgramId = new PublicKey(programId);
+
+  return tx.meta.innerInstructions
+    .flatMap(({ instructions }) => instructions)
+    .filter(({ programIdIndex }) => accountKeys.get(programIdIndex)!.equals(helloWorldProgramId))
+    .map(({ data }) => Buffer.from(utils.bytes.bs58.decode(data)))
+    .filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+    .map((data) => program.coder.events.decode(utils.bytes.base64.encode(data.subarray(8))))
+    .filter((event): event is HelloWorldEvent => event !== null);
+}
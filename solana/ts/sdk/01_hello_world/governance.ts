@@ -0,0 +1,46 @@
+import { ChainId } from "@certusone/wormhole-sdk";
+import { PublicKey, PublicKeyInitData } from "@solana/web3.js";
+
+// AKA `b"HelloWorld"`, left-padded with zeros.
+export const GOVERNANCE_MODULE = Buffer.concat([Buffer.alloc(22), Buffer.from("HelloWorld")]);
+
+export type GovernanceAction =
+  | { registerEmitter: { chain: ChainId; address: Buffer } }
+  | { setFee: { appFee: bigint; feeRecipient: PublicKeyInitData } }
+  | { pause: { outbound: boolean; inbound: boolean } }
+  | { transferOwnership: { newOwner: PublicKeyInitData } };
+
+// Encodes a payload for `execute_governance`, which should be emitted by the
+// governance emitter. Integers are big endian.
+export function encodeGovernancePayload(targetChain: ChainId, action: GovernanceAction): Buffer {
+  const header = (actionId: number) => {
+    const buf = Buffer.alloc(3);
+    buf.writeUInt8(actionId, 0);
+    buf.writeUInt16BE(targetChain, 1);
+    return Buffer.concat([GOVERNANCE_MODULE, buf]);
+  };
+
+  if ("registerEmitter" in action) {
+    const chain = Buffer.alloc(2);
+    chain.writeUInt16BE(action.registerEmitter.chain);
+    return Buffer.concat([header(1), chain, action.registerEmitter.address]);
+  } else if ("setFee" in action) {
+    const appFee = Buffer.alloc(8);
+    appFee.writeBigUInt64BE(action.setFee.appFee);
+    return Buffer.concat([
+      header(2),
+      appFee,
+      new PublicKey(action.setFee.feeRecipient).toBuffer(),
+    ]);
+  } else if ("pause" in action) {
+    return Buffer.concat([
+      header(3),
+      Buffer.from([action.pause.outbound ? 1 : 0, action.pause.inbound ? 1 : 0]),
+    ]);
+  } else {
+    return Buffer.concat([
+      header(4),
+      new PublicKey(action.transferOwnership.newOwner).toBuffer(),
+    ]);
+  }
+}
@@ -1,3 +1,5 @@
 export * from "./accounts";
+export * from "./events";
+export * from "./governance";
 export * from "./instructions";
 export * from "./program";
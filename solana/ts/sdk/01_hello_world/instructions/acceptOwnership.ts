@@ -0,0 +1,18 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey } from "../accounts";
+
+export async function createAcceptOwnershipInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  pendingOwner: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .acceptOwnership()
+    .accounts({
+      pendingOwner: new PublicKey(pendingOwner),
+      config: deriveConfigKey(program.programId),
+    })
+    .instruction();
+}
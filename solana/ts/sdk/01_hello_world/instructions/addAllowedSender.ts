@@ -0,0 +1,27 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveAllowedSenderKey, deriveConfigKey } from "../accounts";
+
+// Specify `senderProgramId` to allow a program that sends messages via CPI.
+// The sender must then be the program's sender PDA (see
+// `deriveProgramSenderKey`).
+export async function createAddAllowedSenderInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  owner: PublicKeyInitData,
+  sender: PublicKeyInitData,
+  senderProgramId?: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .addAllowedSender(
+      new PublicKey(sender),
+      senderProgramId === undefined ? null : new PublicKey(senderProgramId)
+    )
+    .accounts({
+      owner: new PublicKey(owner),
+      config: deriveConfigKey(program.programId),
+      allowedSender: deriveAllowedSenderKey(program.programId, sender),
+    })
+    .instruction();
+}
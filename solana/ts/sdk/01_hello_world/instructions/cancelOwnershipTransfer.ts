@@ -0,0 +1,18 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey } from "../accounts";
+
+export async function createCancelOwnershipTransferInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  owner: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .cancelOwnershipTransfer()
+    .accounts({
+      owner: new PublicKey(owner),
+      config: deriveConfigKey(program.programId),
+    })
+    .instruction();
+}
@@ -0,0 +1,26 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { ChainId } from "@certusone/wormhole-sdk";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveReassemblyKey, getReassemblyData } from "../accounts";
+
+export async function createCloseReassemblyInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  closer: PublicKeyInitData,
+  emitterChain: ChainId,
+  emitterAddress: Buffer,
+  messageId: bigint
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .closeReassembly()
+    .accounts({
+      closer: new PublicKey(closer),
+      // The payer of the reassembly receives the reclaimed rent.
+      payer: (
+        await getReassemblyData(connection, programId, emitterChain, emitterAddress, messageId)
+      ).payer,
+      reassembly: deriveReassemblyKey(program.programId, emitterChain, emitterAddress, messageId),
+    })
+    .instruction();
+}
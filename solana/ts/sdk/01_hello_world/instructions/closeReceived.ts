@@ -0,0 +1,22 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { ChainId } from "@certusone/wormhole-sdk";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveReceivedKey } from "../accounts";
+
+export async function createCloseReceivedInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  emitterChain: ChainId,
+  emitterAddress: Buffer,
+  sequence: bigint
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .closeReceived()
+    .accounts({
+      payer: new PublicKey(payer),
+      received: deriveReceivedKey(program.programId, emitterChain, emitterAddress, sequence),
+    })
+    .instruction();
+}
@@ -0,0 +1,50 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey, deriveExecutedKey, deriveForeignEmitterKey } from "../accounts";
+import { ChainId, isBytes, ParsedVaa, parseVaa, SignedVaa } from "@certusone/wormhole-sdk";
+import { derivePostedVaaKey } from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+
+// Action of `GovernanceAction::RegisterEmitter`.
+const ACTION_REGISTER_EMITTER = 1;
+
+export async function createExecuteGovernanceInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  wormholeProgramId: PublicKeyInitData,
+  wormholeMessage: SignedVaa | ParsedVaa
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+
+  const parsed = isBytes(wormholeMessage)
+    ? parseVaa(wormholeMessage)
+    : wormholeMessage;
+
+  // The foreign emitter account is only used for `RegisterEmitter`, which is
+  // encoded as (module, action, target chain, emitter chain, address).
+  const foreignEmitter =
+    parsed.payload[32] == ACTION_REGISTER_EMITTER
+      ? deriveForeignEmitterKey(
+          programId,
+          parsed.payload.readUInt16BE(35) as ChainId,
+          parsed.payload.subarray(37, 69)
+        )
+      : null;
+
+  return program.methods
+    .executeGovernance([...parsed.hash])
+    .accounts({
+      payer: new PublicKey(payer),
+      config: deriveConfigKey(programId),
+      wormholeProgram: new PublicKey(wormholeProgramId),
+      posted: derivePostedVaaKey(wormholeProgramId, parsed.hash),
+      executed: deriveExecutedKey(
+        programId,
+        parsed.emitterChain,
+        parsed.emitterAddress,
+        parsed.sequence
+      ),
+      foreignEmitter,
+    })
+    .instruction();
+}
@@ -1,4 +1,29 @@
+export * from "./acceptOwnership";
+export * from "./addAllowedSender";
+export * from "./cancelOwnershipTransfer";
+export * from "./closeReassembly";
+export * from "./closeReceived";
+export * from "./deregisterEmitter";
+export * from "./executeGovernance";
 export * from "./initialize";
+export * from "./migrateConfig";
+export * from "./migrateEmitter";
+export * from "./migrateLegacyEmitter";
+export * from "./proposeOwner";
 export * from "./receiveMessage";
 export * from "./registerEmitter";
+export * from "./removeAllowedSender";
 export * from "./sendMessage";
+export * from "./sendMessageChunk";
+export * from "./setAllowlistEnabled";
+export * from "./setEmitterAcks";
+export * from "./setEmitterEnabled";
+export * from "./setEmitterMessageAge";
+export * from "./setEmitterOrdered";
+export * from "./setGovernanceEmitter";
+export * from "./setPause";
+export * from "./setRateLimits";
+export * from "./skipToSequence";
+export * from "./updateFeeSettings";
+export * from "./updateMessageSettings";
+export * from "./withdrawFees";
@@ -1,7 +1,7 @@
 import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
 import { getPostMessageCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import { createHelloWorldProgramInterface } from "../program";
-import { deriveConfigKey, deriveWormholeMessageKey } from "../accounts";
+import { deriveConfigKey, deriveTreasuryKey, deriveWormholeMessageKey } from "../accounts";
 
 export async function createInitializeInstruction(
   connection: Connection,
@@ -22,6 +22,7 @@ export async function createInitializeInstruction(
     .accounts({
       owner: new PublicKey(payer),
       config: deriveConfigKey(programId),
+      treasury: deriveTreasuryKey(programId),
       wormholeProgram: new PublicKey(wormholeProgramId),
       ...wormholeAccounts,
     })
@@ -0,0 +1,19 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey, deriveTreasuryKey } from "../accounts";
+
+export async function createMigrateConfigInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  owner: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .migrateConfig()
+    .accounts({
+      owner: new PublicKey(owner),
+      config: deriveConfigKey(program.programId),
+      treasury: deriveTreasuryKey(program.programId),
+    })
+    .instruction();
+}
@@ -0,0 +1,22 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { ChainId } from "@certusone/wormhole-sdk";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey, deriveForeignEmitterKey } from "../accounts";
+
+export async function createMigrateForeignEmitterInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  emitterChain: ChainId,
+  emitterAddress: Buffer
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .migrateEmitter(emitterChain, [...emitterAddress])
+    .accounts({
+      owner: new PublicKey(payer),
+      config: deriveConfigKey(program.programId),
+      foreignEmitter: deriveForeignEmitterKey(program.programId, emitterChain, emitterAddress),
+    })
+    .instruction();
+}
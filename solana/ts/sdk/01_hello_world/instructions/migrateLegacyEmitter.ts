@@ -0,0 +1,27 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { ChainId } from "@certusone/wormhole-sdk";
+import { createHelloWorldProgramInterface } from "../program";
+import {
+  deriveConfigKey,
+  deriveForeignEmitterKey,
+  deriveLegacyForeignEmitterKey,
+} from "../accounts";
+
+export async function createMigrateLegacyForeignEmitterInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  emitterChain: ChainId,
+  emitterAddress: Buffer
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .migrateLegacyEmitter(emitterChain, [...emitterAddress])
+    .accounts({
+      owner: new PublicKey(payer),
+      config: deriveConfigKey(program.programId),
+      legacyEmitter: deriveLegacyForeignEmitterKey(program.programId, emitterChain),
+      foreignEmitter: deriveForeignEmitterKey(program.programId, emitterChain, emitterAddress),
+    })
+    .instruction();
+}
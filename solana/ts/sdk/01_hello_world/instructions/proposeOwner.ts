@@ -0,0 +1,19 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey } from "../accounts";
+
+export async function createProposeOwnerInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  owner: PublicKeyInitData,
+  newOwner: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .proposeOwner(new PublicKey(newOwner))
+    .accounts({
+      owner: new PublicKey(owner),
+      config: deriveConfigKey(program.programId),
+    })
+    .instruction();
+}
@@ -1,9 +1,22 @@
 import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { getPostMessageCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import { createHelloWorldProgramInterface } from "../program";
-import { deriveConfigKey, deriveForeignEmitterKey, deriveReceivedKey } from "../accounts";
+import {
+  deriveAcknowledgedKey,
+  deriveAckMessageKey,
+  deriveConfigKey,
+  deriveForeignEmitterKey,
+  deriveLegacyReceivedKey,
+  deriveReassemblyKey,
+  deriveReceivedKey,
+} from "../accounts";
 import { isBytes, ParsedVaa, parseVaa, SignedVaa } from "@certusone/wormhole-sdk";
 import { derivePostedVaaKey } from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
 
+// Payload IDs of `HelloWorldMessage::Ack` and `HelloWorldMessage::Chunk`.
+const PAYLOAD_ID_ACK = 2;
+const PAYLOAD_ID_CHUNK = 3;
+
 export async function createReceiveMessageInstruction(
   connection: Connection,
   programId: PublicKeyInitData,
@@ -17,19 +30,60 @@ export async function createReceiveMessageInstruction(
     ? parseVaa(wormholeMessage)
     : wormholeMessage;
 
+  // The acknowledged account is only created for an `Ack`, which is
+  // encoded as (payload ID, emitter chain, sequence, VAA hash).
+  const acknowledged =
+    parsed.payload[0] == PAYLOAD_ID_ACK
+      ? deriveAcknowledgedKey(
+          programId,
+          parsed.emitterChain,
+          parsed.emitterAddress,
+          parsed.payload.readBigUInt64BE(3)
+        )
+      : null;
+
+  // The reassembly account is only used for a `Chunk`, which is encoded as
+  // (payload ID, message ID, index, total, hash, data).
+  const reassembly =
+    parsed.payload[0] == PAYLOAD_ID_CHUNK
+      ? deriveReassemblyKey(
+          programId,
+          parsed.emitterChain,
+          parsed.emitterAddress,
+          parsed.payload.readBigUInt64BE(1)
+        )
+      : null;
+
+  // An `Ack` is posted with this message account if the foreign emitter
+  // requested acknowledgements.
+  const wormholeAccounts = getPostMessageCpiAccounts(
+    programId,
+    wormholeProgramId,
+    payer,
+    deriveAckMessageKey(programId, parsed.emitterChain, parsed.emitterAddress, parsed.sequence)
+  );
+
   return program.methods
     .receiveMessage([...parsed.hash])
     .accounts({
-      payer: new PublicKey(payer),
       config: deriveConfigKey(programId),
       wormholeProgram: new PublicKey(wormholeProgramId),
       posted: derivePostedVaaKey(wormholeProgramId, parsed.hash),
-      foreignEmitter: deriveForeignEmitterKey(programId, parsed.emitterChain),
+      foreignEmitter: deriveForeignEmitterKey(
+        programId,
+        parsed.emitterChain,
+        parsed.emitterAddress
+      ),
       received: deriveReceivedKey(
         programId,
         parsed.emitterChain,
+        parsed.emitterAddress,
         parsed.sequence
       ),
+      legacyReceived: deriveLegacyReceivedKey(programId, parsed.emitterChain, parsed.sequence),
+      acknowledged,
+      reassembly,
+      ...wormholeAccounts,
     })
     .instruction();
 }
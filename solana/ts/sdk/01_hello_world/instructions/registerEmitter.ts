@@ -16,7 +16,7 @@ export async function createRegisterForeignEmitterInstruction(
     .accounts({
       owner: new PublicKey(payer),
       config: deriveConfigKey(program.programId),
-      foreignEmitter: deriveForeignEmitterKey(program.programId, emitterChain),
+      foreignEmitter: deriveForeignEmitterKey(program.programId, emitterChain, emitterAddress),
     })
     .instruction();
 }
@@ -0,0 +1,20 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveAllowedSenderKey, deriveConfigKey } from "../accounts";
+
+export async function createRemoveAllowedSenderInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  owner: PublicKeyInitData,
+  sender: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .removeAllowedSender(new PublicKey(sender))
+    .accounts({
+      owner: new PublicKey(owner),
+      config: deriveConfigKey(program.programId),
+      allowedSender: deriveAllowedSenderKey(program.programId, sender),
+    })
+    .instruction();
+}
@@ -1,23 +1,33 @@
 import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
 import { getPostMessageCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { BN } from "@coral-xyz/anchor";
 import { createHelloWorldProgramInterface } from "../program";
-import { deriveConfigKey, deriveWormholeMessageKey } from "../accounts";
-import { getProgramSequenceTracker } from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+import {
+  deriveAllowedSenderKey,
+  deriveConfigKey,
+  deriveRateLimitKey,
+  deriveSentMessageKey,
+  deriveTreasuryKey,
+  getConfigData,
+} from "../accounts";
 
 export async function createSendMessageInstruction(
   connection: Connection,
   programId: PublicKeyInitData,
   payer: PublicKeyInitData,
   wormholeProgramId: PublicKeyInitData,
-  helloMessage: Buffer
+  helloMessage: Buffer,
+  nonce: bigint,
+  finalized: boolean = false,
+  // Defaults to the payer. Must sign the transaction.
+  sender: PublicKeyInitData = payer
 ): Promise<TransactionInstruction> {
   const program = createHelloWorldProgramInterface(connection, programId);
+  const config = await getConfigData(connection, programId);
 
-  // get sequence
-  const message = await getProgramSequenceTracker(connection, programId, wormholeProgramId)
-  .then((tracker) =>
-    deriveWormholeMessageKey(programId, tracker.sequence + 1n)
-  );
+  // The message account is derived from the payer and a nonce, which must be
+  // unique for this payer.
+  const message = deriveSentMessageKey(programId, payer, nonce);
   const wormholeAccounts = getPostMessageCpiAccounts(
     programId,
     wormholeProgramId,
@@ -25,9 +35,15 @@ export async function createSendMessageInstruction(
     message
   );
   return program.methods
-    .sendMessage(helloMessage)
+    .sendMessage(new BN(nonce.toString()), helloMessage, finalized)
     .accounts({
       config: deriveConfigKey(programId),
+      globalRateLimit:
+        config.globalRateLimit.capacity > 0n ? deriveRateLimitKey(programId) : null,
+      sender: new PublicKey(sender),
+      allowedSender: config.allowlistEnabled ? deriveAllowedSenderKey(programId, sender) : null,
+      senderRateLimit: deriveRateLimitKey(programId, sender),
+      treasury: deriveTreasuryKey(programId),
       wormholeProgram: new PublicKey(wormholeProgramId),
       ...wormholeAccounts,
     })
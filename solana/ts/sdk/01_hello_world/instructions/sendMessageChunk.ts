@@ -0,0 +1,69 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { getPostMessageCpiAccounts } from "@certusone/wormhole-sdk/lib/cjs/solana";
+import { keccak256 } from "@certusone/wormhole-sdk/lib/cjs/utils";
+import { BN } from "@coral-xyz/anchor";
+import { createHelloWorldProgramInterface } from "../program";
+import {
+  deriveAllowedSenderKey,
+  deriveConfigKey,
+  deriveRateLimitKey,
+  deriveSentMessageKey,
+  deriveTreasuryKey,
+  getConfigData,
+} from "../accounts";
+
+// Every chunk except the last has this length.
+export const CHUNK_MAX_LENGTH = 512;
+
+// Returns one instruction per chunk. Each instruction should be sent in its
+// own transaction. Chunk `i` uses `firstNonce + i` as its nonce.
+export async function createSendMessageChunkInstructions(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  payer: PublicKeyInitData,
+  wormholeProgramId: PublicKeyInitData,
+  message: Buffer,
+  messageId: bigint,
+  firstNonce: bigint,
+  // Defaults to the payer. Must sign each transaction.
+  sender: PublicKeyInitData = payer
+): Promise<TransactionInstruction[]> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+
+  const config = await getConfigData(connection, programId);
+  const hash = [...keccak256(message)];
+  const total = Math.ceil(message.length / CHUNK_MAX_LENGTH);
+
+  return Promise.all(
+    [...Array(total).keys()].map((index) => {
+      const nonce = firstNonce + BigInt(index);
+      const wormholeAccounts = getPostMessageCpiAccounts(
+        programId,
+        wormholeProgramId,
+        payer,
+        deriveSentMessageKey(programId, payer, nonce)
+      );
+      return program.methods
+        .sendMessageChunk(
+          new BN(nonce.toString()),
+          new BN(messageId.toString()),
+          index,
+          total,
+          hash,
+          message.subarray(index * CHUNK_MAX_LENGTH, (index + 1) * CHUNK_MAX_LENGTH)
+        )
+        .accounts({
+          config: deriveConfigKey(programId),
+          globalRateLimit:
+            config.globalRateLimit.capacity > 0n ? deriveRateLimitKey(programId) : null,
+          sender: new PublicKey(sender),
+          allowedSender: config.allowlistEnabled ? deriveAllowedSenderKey(programId, sender) : null,
+          senderRateLimit: deriveRateLimitKey(programId, sender),
+          treasury: deriveTreasuryKey(programId),
+          wormholeProgram: new PublicKey(wormholeProgramId),
+          ...wormholeAccounts,
+        })
+        .instruction();
+    })
+  );
+}
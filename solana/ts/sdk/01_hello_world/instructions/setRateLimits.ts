@@ -0,0 +1,26 @@
+import { Connection, PublicKey, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey, deriveRateLimitKey, RateLimitParams } from "../accounts";
+
+export async function createSetRateLimitsInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData,
+  owner: PublicKeyInitData,
+  global: RateLimitParams,
+  sender: RateLimitParams
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  const toArgs = (params: RateLimitParams) => ({
+    capacity: new BN(params.capacity.toString()),
+    secondsPerToken: new BN(params.secondsPerToken.toString()),
+  });
+  return program.methods
+    .setRateLimits(toArgs(global), toArgs(sender))
+    .accounts({
+      owner: new PublicKey(owner),
+      config: deriveConfigKey(program.programId),
+      globalRateLimit: deriveRateLimitKey(program.programId),
+    })
+    .instruction();
+}
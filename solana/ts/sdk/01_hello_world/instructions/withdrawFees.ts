@@ -0,0 +1,18 @@
+import { Connection, PublicKeyInitData, TransactionInstruction } from "@solana/web3.js";
+import { createHelloWorldProgramInterface } from "../program";
+import { deriveConfigKey, deriveTreasuryKey, getConfigData } from "../accounts";
+
+export async function createWithdrawFeesInstruction(
+  connection: Connection,
+  programId: PublicKeyInitData
+): Promise<TransactionInstruction> {
+  const program = createHelloWorldProgramInterface(connection, programId);
+  return program.methods
+    .withdrawFees()
+    .accounts({
+      config: deriveConfigKey(program.programId),
+      treasury: deriveTreasuryKey(program.programId),
+      feeRecipient: (await getConfigData(connection, programId)).feeRecipient,
+    })
+    .instruction();
+}
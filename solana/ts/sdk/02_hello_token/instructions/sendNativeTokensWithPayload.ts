@@ -12,6 +12,8 @@ import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { SendTokensParams } from "./types";
 import { BN } from "@coral-xyz/anchor";
 
+// Tokens are sent from the payer's associated token account unless
+// `fromTokenAccount` is specified.
 export async function createSendNativeTokensWithPayloadInstruction(
   connection: Connection,
   programId: PublicKeyInitData,
@@ -19,7 +21,8 @@ export async function createSendNativeTokensWithPayloadInstruction(
   tokenBridgeProgramId: PublicKeyInitData,
   wormholeProgramId: PublicKeyInitData,
   mint: PublicKeyInitData,
-  params: SendTokensParams
+  params: SendTokensParams,
+  fromTokenAccount?: PublicKeyInitData
 ): Promise<TransactionInstruction> {
   const program = createHelloTokenProgramInterface(connection, programId);
 
@@ -32,10 +35,9 @@ export async function createSendNativeTokensWithPayloadInstruction(
       deriveTokenTransferMessageKey(programId, tracker.sequence + 1n)
     )
     .then((message) => {
-      const fromTokenAccount = getAssociatedTokenAddressSync(
-        new PublicKey(mint),
-        new PublicKey(payer)
-      );
+      const from = fromTokenAccount === undefined
+        ? getAssociatedTokenAddressSync(new PublicKey(mint), new PublicKey(payer))
+        : new PublicKey(fromTokenAccount);
       const tmpTokenAccount = deriveTmpTokenAccountKey(programId, mint);
       const tokenBridgeAccounts = getTransferNativeWithPayloadCpiAccounts(
         programId,
@@ -43,7 +45,7 @@ export async function createSendNativeTokensWithPayloadInstruction(
         wormholeProgramId,
         payer,
         message,
-        fromTokenAccount,
+        from,
         mint
       );
 
@@ -60,6 +62,7 @@ export async function createSendNativeTokensWithPayloadInstruction(
             programId,
             params.recipientChain
           ),
+          fromTokenAccount: from,
           tmpTokenAccount,
           tokenBridgeProgram: new PublicKey(tokenBridgeProgramId),
           ...tokenBridgeAccounts,
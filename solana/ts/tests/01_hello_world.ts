@@ -14,6 +14,7 @@ import {
   getPostMessageCpiAccounts,
 } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import * as wormhole from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+import { keccak256 } from "@certusone/wormhole-sdk/lib/cjs/utils";
 import * as helloWorld from "../sdk/01_hello_world";
 import {
   LOCALHOST,
@@ -34,6 +35,7 @@ describe(" 1: Hello World", function() {
     requestAirdrop,
     guardianSign,
     postSignedMsgAsVaaOnSolana,
+    sendAndConfirmIx,
     expectIxToSucceed,
     expectIxToFailWithError,
   } = boilerPlateReduction(connection, payer);
@@ -41,14 +43,27 @@ describe(" 1: Hello World", function() {
   // foreign emitter info
   const realForeignEmitterChain = CHAINS.ethereum;
   const realForeignEmitterAddress = Buffer.alloc(32, "deadbeef", "hex");
+  // Emitter being migrated away from. Both are trusted until it is retired.
+  const oldForeignEmitterAddress = Buffer.alloc(32, "fbadc0de", "hex");
 
   const realConfig = helloWorld.deriveConfigKey(HELLO_WORLD_PID);
   const realForeignEmitter =
-    helloWorld.deriveForeignEmitterKey(HELLO_WORLD_PID, realForeignEmitterChain);
+    helloWorld.deriveForeignEmitterKey(
+      HELLO_WORLD_PID,
+      realForeignEmitterChain,
+      realForeignEmitterAddress
+    );
   const program = helloWorld.createHelloWorldProgramInterface(connection, HELLO_WORLD_PID);
 
+  const getEvent = async (signature: string, name: string) => {
+    const events = await helloWorld.getHelloWorldEvents(connection, HELLO_WORLD_PID, signature);
+    const event = events.find((event) => event.name === name);
+    expect(event).is.not.undefined;
+    return event!.data;
+  };
+
   const getFalseAccountsAndCheckReal = (
-    seeds: string | [string, Buffer],
+    seeds: string | [string, ...Buffer[]],
     programId: PublicKey,
     realAccount: PublicKey,
   ) => {
@@ -62,7 +77,7 @@ describe(" 1: Hello World", function() {
             [
               ...(typeof seeds === "string"
                 ? [Buffer.from(seeds)]
-                : [Buffer.from(seeds[0]), seeds[1]]
+                : [Buffer.from(seeds[0]), ...(seeds.slice(1) as Buffer[])]
               ),
               bumpByte
             ],
@@ -88,6 +103,7 @@ describe(" 1: Hello World", function() {
     const realInitializeAccounts = {
       owner: payer.publicKey,
       config: realConfig,
+      treasury: helloWorld.deriveTreasuryKey(HELLO_WORLD_PID),
       wormholeProgram: CORE_BRIDGE_PID,
       wormholeBridge: wormholeCpi.wormholeBridge,
       wormholeFeeCollector: wormholeCpi.wormholeFeeCollector,
@@ -120,6 +136,11 @@ describe(" 1: Hello World", function() {
         ["config", HELLO_WORLD_PID, realConfig],
         "Error Code: ConstraintSeeds. Error Number: 2006."
       ],
+      [
+        "treasury",
+        ["treasury", HELLO_WORLD_PID, helloWorld.deriveTreasuryKey(HELLO_WORLD_PID)],
+        "Error Code: ConstraintSeeds. Error Number: 2006."
+      ],
       [
         "wormholeBridge",
         ["Bridge", CORE_BRIDGE_PID, wormholeCpi.wormholeBridge],
@@ -175,6 +196,11 @@ describe(" 1: Hello World", function() {
         wormhole.getWormholeDerivedAccounts(HELLO_WORLD_PID, CORE_BRIDGE_PID);
       expect(configData.wormhole.bridge).deep.equals(wormholeBridge);
       expect(configData.wormhole.feeCollector).deep.equals(wormholeFeeCollector);
+
+      // The treasury is created with the config, so senders do not create it.
+      expect(
+        await connection.getAccountInfo(helloWorld.deriveTreasuryKey(HELLO_WORLD_PID))
+      ).is.not.null;
     });
 
     it("Cannot Call Instruction Again: initialize", async function() {
@@ -182,6 +208,146 @@ describe(" 1: Hello World", function() {
     });
   });
 
+  describe("Transfer Ownership", function() {
+    const newOwner = Keypair.generate();
+
+    const getPendingOwner = async () =>
+      (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).pendingOwner;
+
+    it("Config Can Only Be Migrated Once", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createMigrateConfigInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey
+        ),
+        "ConfigAlreadyMigrated"
+      );
+    });
+
+    it("Cannot Propose Owner Without Owner", async function() {
+      await requestAirdrop(newOwner.publicKey);
+      await expectIxToFailWithError(
+        await helloWorld.createProposeOwnerInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          newOwner.publicKey,
+          newOwner.publicKey
+        ),
+        "OwnerOnly",
+        newOwner
+      );
+    });
+
+    it("Cannot Propose Current Owner", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createProposeOwnerInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          payer.publicKey
+        ),
+        "InvalidNewOwner"
+      );
+    });
+
+    it("Cannot Cancel Without Pending Owner", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createCancelOwnershipTransferInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey
+        ),
+        "NoPendingOwner"
+      );
+    });
+
+    it("Propose and Cancel Ownership Transfer", async function() {
+      await expectIxToSucceed(
+        helloWorld.createProposeOwnerInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          newOwner.publicKey
+        )
+      );
+      expect(await getPendingOwner()).deep.equals(newOwner.publicKey);
+
+      await expectIxToSucceed(
+        helloWorld.createCancelOwnershipTransferInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey
+        )
+      );
+      expect(await getPendingOwner()).is.null;
+
+      await expectIxToFailWithError(
+        await helloWorld.createAcceptOwnershipInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          newOwner.publicKey
+        ),
+        "NotPendingOwner",
+        newOwner
+      );
+    });
+
+    it("Transfer Ownership and Transfer It Back", async function() {
+      await expectIxToSucceed(
+        helloWorld.createProposeOwnerInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          newOwner.publicKey
+        )
+      );
+      await expectIxToSucceed(
+        helloWorld.createAcceptOwnershipInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          newOwner.publicKey
+        ),
+        newOwner
+      );
+
+      const configData = await helloWorld.getConfigData(connection, HELLO_WORLD_PID);
+      expect(configData.owner).deep.equals(newOwner.publicKey);
+      expect(configData.pendingOwner).is.null;
+
+      // The previous owner is no longer permitted.
+      await expectIxToFailWithError(
+        await helloWorld.createProposeOwnerInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          payer.publicKey
+        ),
+        "OwnerOnly"
+      );
+
+      await expectIxToSucceed(
+        helloWorld.createProposeOwnerInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          newOwner.publicKey,
+          payer.publicKey
+        ),
+        newOwner
+      );
+      await expectIxToSucceed(
+        helloWorld.createAcceptOwnershipInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey
+        )
+      );
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).owner
+      ).deep.equals(payer.publicKey);
+    });
+  });
+
   describe("Register Foreign Emitter", function() {
     const realRegisterEmitterAccounts = {
       owner: payer.publicKey,
@@ -233,7 +399,8 @@ describe(" 1: Hello World", function() {
               const buf = Buffer.alloc(2);
               buf.writeUInt16LE(realForeignEmitterChain);
               return buf;
-            })()
+            })(),
+            realForeignEmitterAddress
           ],
           HELLO_WORLD_PID,
           realForeignEmitter
@@ -308,11 +475,11 @@ describe(" 1: Hello World", function() {
     });
 
     [
-      Buffer.alloc(32, "fbadc0de", "hex"),
+      oldForeignEmitterAddress,
       realForeignEmitterAddress,
     ]
     .forEach((emitterAddress) =>
-      it(`Register ${emitterAddress === realForeignEmitterAddress ? "Final" : "Random"} Emitter`,
+      it(`Register ${emitterAddress === realForeignEmitterAddress ? "New" : "Old"} Emitter`,
       async function() {
         await expectIxToSucceed(
           helloWorld.createRegisterForeignEmitterInstruction(
@@ -328,12 +495,73 @@ describe(" 1: Hello World", function() {
           await helloWorld.getForeignEmitterData(
             connection,
             HELLO_WORLD_PID,
-            realForeignEmitterChain
+            realForeignEmitterChain,
+            emitterAddress
           );
         expect(chain).equals(realForeignEmitterChain);
         expect(address).deep.equals(emitterAddress);
       })
     );
+
+    it("Emit EmitterRegistered Event", async function() {
+      // Registering an emitter again is allowed, and emits the event again.
+      const signature = await sendAndConfirmIx(
+        helloWorld.createRegisterForeignEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress
+        )
+      );
+
+      const {chain, address} = await getEvent(signature, "emitterRegistered");
+      expect(chain).equals(realForeignEmitterChain);
+      expect(Buffer.from(address)).deep.equals(realForeignEmitterAddress);
+    });
+
+    it("Cannot Set Emitter Enabled Without Owner", async function() {
+      const nonOwner = Keypair.generate();
+      await requestAirdrop(nonOwner.publicKey);
+      await expectIxToFailWithError(
+        await helloWorld.createSetForeignEmitterEnabledInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          nonOwner.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          false
+        ),
+        "OwnerOnly",
+        nonOwner
+      );
+    });
+
+    it("Emitter Can Only Be Migrated Once", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createMigrateForeignEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress
+        ),
+        "EmitterAlreadyMigrated"
+      );
+    });
+
+    it("Cannot Migrate Legacy Emitter That Was Never Registered", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createMigrateLegacyForeignEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          Buffer.alloc(32, "fadefade", "hex")
+        ),
+        "InvalidForeignEmitter"
+      );
+    });
   });
 
   describe("Send Message", function() {
@@ -346,16 +574,47 @@ describe(" 1: Hello World", function() {
           HELLO_WORLD_PID,
           payer.publicKey,
           CORE_BRIDGE_PID,
-          helloMessage
+          helloMessage,
+          0n // nonce
         ),
         "IO Error: message exceeds 512 bytes"
       );
     });
 
+    it("Cannot Send Message While Paused", async function() {
+      const setPauseOutboundIx = (paused: boolean) =>
+        helloWorld.createSetPauseOutboundInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          paused
+        );
+
+      await expectIxToSucceed(setPauseOutboundIx(true));
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).pauseOutbound
+      ).is.true;
+
+      await expectIxToFailWithError(
+        await helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          0n // nonce
+        ),
+        "OutboundPaused"
+      );
+
+      await expectIxToSucceed(setPauseOutboundIx(false));
+    });
+
     it("Finally Send Message", async function() {
       const helloMessage = Buffer.from("All your base are belong to us");
+      const nonce = 0n;
 
-      // save message count to grab posted message later
+      // save message count to check the posted message's sequence later
       const sequence = (
         await wormhole.getProgramSequenceTracker(connection, HELLO_WORLD_PID, CORE_BRIDGE_PID)
       ).value() + 1n;
@@ -366,118 +625,1313 @@ describe(" 1: Hello World", function() {
           HELLO_WORLD_PID,
           payer.publicKey,
           CORE_BRIDGE_PID,
-          helloMessage
+          helloMessage,
+          nonce
         )
       );
 
-      const {payload} =
+      // The message account is derived from the payer and nonce, and the
+      // Wormhole program saves the message's sequence in it.
+      const {payload, sequence: postedSequence} =
         (await wormhole.getPostedMessage(
           connection,
-          helloWorld.deriveWormholeMessageKey(HELLO_WORLD_PID, sequence)
+          helloWorld.deriveSentMessageKey(HELLO_WORLD_PID, payer.publicKey, nonce)
         )).message;
 
+      expect(postedSequence).equals(sequence);
       expect(payload.readUint8(0)).equals(1); // payload ID
       expect(payload.readUint16BE(1)).equals(helloMessage.length);
       expect(payload.subarray(3)).deep.equals(helloMessage);
     });
-  });
-
-  describe("Receive Message", function() {
-    const realEmitter = new mock.MockEmitter(
-      realForeignEmitterAddress.toString("hex"),
-      realForeignEmitterChain
-    );
-
-    const batchId = 0;
-    const message = Buffer.from("Somebody set up us the bomb");
-    
-    const createPayload = (options?: {payloadId?: number; length?: number}) => {
-      const length = (options?.length ?? message.length);
-      const buf = Buffer.alloc(3 + length);
-      buf.writeUint8(options?.payloadId ?? 1, 0);
-      buf.writeUint16BE(length, 1);
-      message.copy(buf, 3);
-      return buf;
-    };
 
-    const publishAndSign = (payload: Buffer, emitter: mock.MockEmitter) => {
-      const finality = 1;
-      return guardianSign(emitter.publishMessage(batchId, payload, finality));
-    }
-
-    const createAndReceiveIx = (signedMsg: Buffer) =>
-      helloWorld.createReceiveMessageInstruction(
-        connection,
-        HELLO_WORLD_PID,
-        payer.publicKey,
-        CORE_BRIDGE_PID,
-        signedMsg
+    it("Emit MessageSent Event", async function() {
+      const helloMessage = Buffer.from("All your base are belong to us");
+      const nonce = 2n;
+      const signature = await sendAndConfirmIx(
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          CORE_BRIDGE_PID,
+          helloMessage,
+          nonce
+        )
       );
-    
-    ([
-      [
-        "Unregistered Emitter",
-        new mock.MockEmitter(
-          Buffer.alloc(32, "deafbeef").toString("hex"),
-          realForeignEmitterChain
-        ),
-        createPayload(),
-        "InvalidForeignEmitter"
-      ],
-      [
-        "Invalid Payload ID",
-        realEmitter,
-        createPayload({payloadId: 2}),
-        "IO Error: invalid payload ID"
-      ],
-      [
-        "Payload ID == 0 (Alive)",
-        realEmitter,
-        (() => {
-          const buf = Buffer.alloc(33);
-          buf.writeUint8(0, 0); // payload ID
-          HELLO_WORLD_PID.toBuffer().copy(buf, 1);
-          return buf;
-        })(),
-        "InvalidMessage"
-      ],
-      [
-        "Length > 512",
-        realEmitter,
-        createPayload({length: 513}),
-        "IO Error: message exceeds 512 bytes"
-      ]
-    ] as [string, mock.MockEmitter, Buffer, string][])
-    .forEach(([testcase, emitter, payload, error]) =>
-      it(`Cannot Receive Message With ${testcase}`, async function() {
-        const signedMsg = publishAndSign(payload, emitter);
-        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
-        await expectIxToFailWithError(await createAndReceiveIx(signedMsg), error);
-      })
-    );
 
-    const signedMsg = publishAndSign(createPayload(), realEmitter);
+      const {payload, sequence} =
+        (await wormhole.getPostedMessage(
+          connection,
+          helloWorld.deriveSentMessageKey(HELLO_WORLD_PID, payer.publicKey, nonce)
+        )).message;
 
-    it("Post Wormhole Message", async function() {
-      await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+      const event = await getEvent(signature, "messageSent");
+      expect(BigInt(event.sequence.toString())).equals(sequence);
+      expect(Buffer.from(event.payloadHash)).deep.equals(keccak256(payload));
+      expect(event.payer.equals(payer.publicKey)).is.true;
     });
 
-    it("Finally Receive Message", async function() {
-      await expectIxToSucceed(createAndReceiveIx(signedMsg));
-
-      const parsed = parseVaa(signedMsg);
-      const received = await helloWorld.getReceivedData(
-        connection,
-        HELLO_WORLD_PID,
-        parsed.emitterChain as ChainId, // don't do this at home, kids
-        parsed.sequence
+    it("Cannot Update Message Settings With Invalid Finality", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createUpdateMessageSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0, // batchId
+          2, // finality
+          0n // finalizedFee
+        ),
+        "InvalidFinality"
       );
-      expect(received.batchId).equals(batchId);
-      expect(received.message).deep.equals(message);
     });
 
-    it("Cannot Call Instruction Again With Same Wormhole Message", async function() {
-      await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "already in use");
+    it("Send Finalized Message For A Fee", async function() {
+      const finalizedFee = 1_000_000n;
+      await expectIxToSucceed(
+        helloWorld.createUpdateMessageSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0, // batchId
+          0, // finality (confirmed)
+          finalizedFee
+        )
+      );
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).finalizedFee
+      ).equals(finalizedFee);
+
+      const sender = Keypair.generate();
+      await requestAirdrop(sender.publicKey);
+      const treasury = helloWorld.deriveTreasuryKey(HELLO_WORLD_PID);
+      const treasuryBalanceBefore = await connection.getBalance(treasury);
+
+      const nonce = 1n;
+      await expectIxToSucceed(
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          sender.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          nonce,
+          true // finalized
+        ),
+        sender
+      );
+
+      const {consistencyLevel} =
+        (await wormhole.getPostedMessage(
+          connection,
+          helloWorld.deriveSentMessageKey(HELLO_WORLD_PID, sender.publicKey, nonce)
+        )).message;
+      expect(consistencyLevel).equals(1); // finalized
+      expect(await connection.getBalance(treasury))
+        .equals(treasuryBalanceBefore + Number(finalizedFee));
+
+      // Reset the fee.
+      await expectIxToSucceed(
+        helloWorld.createUpdateMessageSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0, // batchId
+          0, // finality (confirmed)
+          0n // finalizedFee
+        )
+      );
+    });
+
+    it("Send Messages Concurrently", async function() {
+      const senders = range(4).map(() => Keypair.generate());
+      await Promise.all(senders.map((sender) => requestAirdrop(sender.publicKey)));
+
+      await Promise.all(
+        senders.map(async (sender) =>
+          expectIxToSucceed(
+            helloWorld.createSendMessageInstruction(
+              connection,
+              HELLO_WORLD_PID,
+              sender.publicKey,
+              CORE_BRIDGE_PID,
+              Buffer.from("All your base are belong to us"),
+              0n // nonce
+            ),
+            sender
+          )
+        )
+      );
+
+      const sequences = await Promise.all(
+        senders.map(async (sender) =>
+          (await wormhole.getPostedMessage(
+            connection,
+            helloWorld.deriveSentMessageKey(HELLO_WORLD_PID, sender.publicKey, 0n)
+          )).message.sequence
+        )
+      );
+      expect(new Set(sequences).size).equals(senders.length);
+    });
+
+    it("Cannot Set Rate Limit Without Refill Period", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createSetRateLimitsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          {capacity: 0n, secondsPerToken: 0n}, // global
+          {capacity: 2n, secondsPerToken: 0n} // sender
+        ),
+        "InvalidRateLimit"
+      );
+    });
+
+    it("Rate Limit Sender", async function() {
+      const noLimit = {capacity: 0n, secondsPerToken: 0n};
+      const senderLimit = {capacity: 2n, secondsPerToken: 3600n};
+      await expectIxToSucceed(
+        helloWorld.createSetRateLimitsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          noLimit,
+          senderLimit
+        )
+      );
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).senderRateLimit
+      ).deep.equals(senderLimit);
+
+      const sender = Keypair.generate();
+      await requestAirdrop(sender.publicKey);
+      const createSendIx = (nonce: bigint) =>
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          sender.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          nonce
+        );
+
+      await expectIxToSucceed(createSendIx(0n), sender);
+      await expectIxToSucceed(createSendIx(1n), sender);
+      await expectIxToFailWithError(await createSendIx(2n), "RateLimited", sender);
+      const wait = await sendAndConfirmIx(await createSendIx(2n), sender).then(
+        () => expect.fail("expected the send to be rate limited"),
+        (error: any) => {
+          const match = /Next send allowed in (\d+) seconds/.exec(error.logs);
+          expect(match).is.not.null;
+          return Number(match![1]);
+        }
+      );
+      expect(wait).is.greaterThan(0).and.at.most(3600);
+      expect(
+        (await helloWorld.getRateLimitData(connection, HELLO_WORLD_PID, sender.publicKey)).tokens
+      ).equals(0n);
+
+      // Other senders have their own bucket.
+      await expectIxToSucceed(
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          3n // nonce
+        )
+      );
+
+      await expectIxToSucceed(
+        helloWorld.createSetRateLimitsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          noLimit,
+          noLimit
+        )
+      );
+      await expectIxToSucceed(createSendIx(2n), sender);
+    });
+
+    it("Cannot Send Without Global Rate Limit Account", async function() {
+      const noLimit = {capacity: 0n, secondsPerToken: 0n};
+      // Built while the global limit is off, so the global bucket is omitted.
+      const sendIx = await helloWorld.createSendMessageInstruction(
+        connection,
+        HELLO_WORLD_PID,
+        payer.publicKey,
+        CORE_BRIDGE_PID,
+        Buffer.from("All your base are belong to us"),
+        4n // nonce
+      );
+
+      await expectIxToSucceed(
+        helloWorld.createSetRateLimitsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          {capacity: 10n, secondsPerToken: 60n},
+          noLimit
+        )
+      );
+      expect(
+        await connection.getAccountInfo(helloWorld.deriveRateLimitKey(HELLO_WORLD_PID))
+      ).is.not.null;
+      await expectIxToFailWithError(sendIx, "MissingGlobalRateLimit");
+
+      await expectIxToSucceed(
+        helloWorld.createSetRateLimitsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          noLimit,
+          noLimit
+        )
+      );
+      await expectIxToSucceed(sendIx);
+    });
+
+    it("Cannot Set Default Fee Recipient", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createUpdateFeeSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0n, // appFee
+          PublicKey.default
+        ),
+        "InvalidFeeRecipient"
+      );
+    });
+
+    it("Collect And Withdraw Application Fee", async function() {
+      const appFee = 1_000_000n;
+      const feeRecipient = Keypair.generate().publicKey;
+      await expectIxToSucceed(
+        helloWorld.createUpdateFeeSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          appFee,
+          feeRecipient
+        )
+      );
+      const config = await helloWorld.getConfigData(connection, HELLO_WORLD_PID);
+      expect(config.appFee).equals(appFee);
+      expect(config.feeRecipient.equals(feeRecipient)).is.true;
+
+      const treasury = helloWorld.deriveTreasuryKey(HELLO_WORLD_PID);
+      const treasuryBalanceBefore = await connection.getBalance(treasury);
+      await expectIxToSucceed(
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          4n // nonce
+        )
+      );
+      expect(await connection.getBalance(treasury))
+        .equals(treasuryBalanceBefore + Number(appFee));
+
+      // Anyone can withdraw, but fees only go to the fee recipient.
+      await expectIxToSucceed(
+        helloWorld.createWithdrawFeesInstruction(connection, HELLO_WORLD_PID)
+      );
+      expect(await connection.getBalance(feeRecipient))
+        .is.greaterThanOrEqual(Number(appFee));
+      await expectIxToFailWithError(
+        await helloWorld.createWithdrawFeesInstruction(connection, HELLO_WORLD_PID),
+        "NoFeesToWithdraw"
+      );
+
+      // Reset the fee settings.
+      await expectIxToSucceed(
+        helloWorld.createUpdateFeeSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0n, // appFee
+          payer.publicKey
+        )
+      );
+    });
+
+    it("Collect And Withdraw Finalized Fee", async function() {
+      const finalizedFee = 1_000_000n;
+      const feeRecipient = Keypair.generate().publicKey;
+      await expectIxToSucceed(
+        helloWorld.createUpdateFeeSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0n, // appFee
+          feeRecipient
+        )
+      );
+      await expectIxToSucceed(
+        helloWorld.createUpdateMessageSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0, // batchId
+          0, // finality (confirmed)
+          finalizedFee
+        )
+      );
+
+      await expectIxToSucceed(
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          7n, // nonce
+          true // finalized
+        )
+      );
+
+      // The finalized fee is withdrawn with the application fees.
+      await expectIxToSucceed(
+        helloWorld.createWithdrawFeesInstruction(connection, HELLO_WORLD_PID)
+      );
+      expect(await connection.getBalance(feeRecipient)).equals(Number(finalizedFee));
+
+      // Reset the fee settings.
+      await expectIxToSucceed(
+        helloWorld.createUpdateMessageSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0, // batchId
+          0, // finality (confirmed)
+          0n // finalizedFee
+        )
+      );
+      await expectIxToSucceed(
+        helloWorld.createUpdateFeeSettingsInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          0n, // appFee
+          payer.publicKey
+        )
+      );
+    });
+
+    it("Cannot Allow Program Sender That Is Not Its PDA", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createAddAllowedSenderInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          Keypair.generate().publicKey, // sender
+          CORE_BRIDGE_PID // senderProgramId
+        ),
+        "InvalidSender"
+      );
+    });
+
+    it("Send Message With Allowlist Enabled", async function() {
+      await expectIxToSucceed(
+        helloWorld.createSetAllowlistEnabledInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          true
+        )
+      );
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).allowlistEnabled
+      ).is.true;
+
+      // A sender other than the payer must sign too.
+      const sender = Keypair.generate();
+      const createSendIx = (nonce: bigint) =>
+        helloWorld.createSendMessageInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          CORE_BRIDGE_PID,
+          Buffer.from("All your base are belong to us"),
+          nonce,
+          false, // finalized
+          sender.publicKey
+        );
+      await expectIxToFailWithError(await createSendIx(5n), "SenderNotAllowed", [payer, sender]);
+
+      await expectIxToSucceed(
+        helloWorld.createAddAllowedSenderInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          sender.publicKey
+        )
+      );
+      const allowedSender =
+        await helloWorld.getAllowedSenderData(connection, HELLO_WORLD_PID, sender.publicKey);
+      expect(allowedSender.sender.equals(sender.publicKey)).is.true;
+      expect(allowedSender.program).is.null;
+      await expectIxToSucceed(createSendIx(5n), [payer, sender]);
+
+      // Programs are allowed by their sender PDA.
+      const senderProgramId = Keypair.generate().publicKey;
+      const programSender = helloWorld.deriveProgramSenderKey(senderProgramId);
+      await expectIxToSucceed(
+        helloWorld.createAddAllowedSenderInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          programSender,
+          senderProgramId
+        )
+      );
+      expect(
+        (await helloWorld.getAllowedSenderData(connection, HELLO_WORLD_PID, programSender))
+          .program!.equals(senderProgramId)
+      ).is.true;
+
+      // Removed senders cannot send anymore.
+      for (const removed of [sender.publicKey, programSender]) {
+        await expectIxToSucceed(
+          helloWorld.createRemoveAllowedSenderInstruction(
+            connection,
+            HELLO_WORLD_PID,
+            payer.publicKey,
+            removed
+          )
+        );
+      }
+      await expectIxToFailWithError(await createSendIx(6n), "SenderNotAllowed", [payer, sender]);
+
+      await expectIxToSucceed(
+        helloWorld.createSetAllowlistEnabledInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          false
+        )
+      );
+      await expectIxToSucceed(createSendIx(6n), [payer, sender]);
+    });
+
+    it("Send Chunked Message", async function() {
+      const largeMessage = Buffer.alloc(1200, "All your base are belong to us");
+      const messageId = 1n;
+      const firstNonce = 100n;
+
+      const ixs = await helloWorld.createSendMessageChunkInstructions(
+        connection,
+        HELLO_WORLD_PID,
+        payer.publicKey,
+        CORE_BRIDGE_PID,
+        largeMessage,
+        messageId,
+        firstNonce
+      );
+      expect(ixs.length).equals(3);
+      for (const ix of ixs) {
+        await expectIxToSucceed(ix);
+      }
+
+      const chunks = await Promise.all(
+        ixs.map(async (_, index) =>
+          (await wormhole.getPostedMessage(
+            connection,
+            helloWorld.deriveSentMessageKey(HELLO_WORLD_PID, payer.publicKey, firstNonce + BigInt(index))
+          )).message.payload
+        )
+      );
+      chunks.forEach((payload, index) => {
+        expect(payload.readUint8(0)).equals(3); // payload ID
+        expect(payload.readBigUInt64BE(1)).equals(messageId);
+        expect(payload.readUint16BE(9)).equals(index);
+        expect(payload.readUint16BE(11)).equals(ixs.length);
+        expect(payload.subarray(13, 45)).deep.equals(keccak256(largeMessage));
+      });
+      expect(Buffer.concat(chunks.map((payload) => payload.subarray(47)))).deep.equals(largeMessage);
+    });
+
+    it("Cannot Send Chunk With Invalid Index", async function() {
+      const [ix] = await helloWorld.createSendMessageChunkInstructions(
+        connection,
+        HELLO_WORLD_PID,
+        payer.publicKey,
+        CORE_BRIDGE_PID,
+        Buffer.from("All your base are belong to us"),
+        2n, // messageId
+        200n // firstNonce
+      );
+      // Overwrite the chunk index (after the discriminator and two u64s) so
+      // that it is not less than the total.
+      ix.data.writeUint16LE(1, 24);
+      await expectIxToFailWithError(ix, "InvalidChunk");
+    });
+  });
+
+  describe("Receive Message", function() {
+    const realEmitter = new mock.MockEmitter(
+      realForeignEmitterAddress.toString("hex"),
+      realForeignEmitterChain
+    );
+
+    const batchId = 0;
+    const message = Buffer.from("Somebody set up us the bomb");
+    
+    const createPayload = (options?: {payloadId?: number; length?: number}) => {
+      const length = (options?.length ?? message.length);
+      const buf = Buffer.alloc(3 + length);
+      buf.writeUint8(options?.payloadId ?? 1, 0);
+      buf.writeUint16BE(length, 1);
+      message.copy(buf, 3);
+      return buf;
+    };
+
+    const publishAndSign = (payload: Buffer, emitter: mock.MockEmitter) => {
+      const finality = 1;
+      return guardianSign(emitter.publishMessage(batchId, payload, finality));
+    }
+
+    const createAndReceiveIx = (signedMsg: Buffer) =>
+      helloWorld.createReceiveMessageInstruction(
+        connection,
+        HELLO_WORLD_PID,
+        payer.publicKey,
+        CORE_BRIDGE_PID,
+        signedMsg
+      );
+    
+    ([
+      [
+        "Unregistered Emitter",
+        new mock.MockEmitter(
+          Buffer.alloc(32, "deafbeef").toString("hex"),
+          realForeignEmitterChain
+        ),
+        createPayload(),
+        "AccountNotInitialized"
+      ],
+      [
+        "Invalid Payload ID",
+        realEmitter,
+        createPayload({payloadId: 255}),
+        "IO Error: invalid payload ID"
+      ],
+      [
+        "Payload ID == 0 (Alive)",
+        realEmitter,
+        (() => {
+          const buf = Buffer.alloc(33);
+          buf.writeUint8(0, 0); // payload ID
+          HELLO_WORLD_PID.toBuffer().copy(buf, 1);
+          return buf;
+        })(),
+        "InvalidMessage"
+      ],
+      [
+        "Length > 512",
+        realEmitter,
+        createPayload({length: 513}),
+        "IO Error: message exceeds 512 bytes"
+      ]
+    ] as [string, mock.MockEmitter, Buffer, string][])
+    .forEach(([testcase, emitter, payload, error]) =>
+      it(`Cannot Receive Message With ${testcase}`, async function() {
+        const signedMsg = publishAndSign(payload, emitter);
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        await expectIxToFailWithError(await createAndReceiveIx(signedMsg), error);
+      })
+    );
+
+    const signedMsg = publishAndSign(createPayload(), realEmitter);
+
+    it("Post Wormhole Message", async function() {
+      await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+    });
+
+    it("Cannot Receive Message While Paused", async function() {
+      const setPauseInboundIx = (paused: boolean) =>
+        helloWorld.createSetPauseInboundInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          paused
+        );
+
+      await expectIxToSucceed(setPauseInboundIx(true));
+      await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "InboundPaused");
+
+      // The message can still be received after unpausing.
+      await expectIxToSucceed(setPauseInboundIx(false));
+    });
+
+    it("Cannot Receive Message From Disabled Emitter", async function() {
+      await expectIxToSucceed(
+        helloWorld.createSetForeignEmitterEnabledInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          false
+        )
+      );
+      expect(
+        (await helloWorld.getForeignEmitterData(
+          connection,
+          HELLO_WORLD_PID,
+          realForeignEmitterChain,
+          realForeignEmitterAddress
+        )).enabled
+      ).is.false;
+
+      await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "ForeignEmitterDisabled");
+
+      // Registering the emitter again does not re-enable it.
+      await expectIxToSucceed(
+        helloWorld.createRegisterForeignEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress
+        )
+      );
+      expect(
+        (await helloWorld.getForeignEmitterData(
+          connection,
+          HELLO_WORLD_PID,
+          realForeignEmitterChain,
+          realForeignEmitterAddress
+        )).enabled
+      ).is.false;
+      await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "ForeignEmitterDisabled");
+
+      await expectIxToSucceed(
+        helloWorld.createSetForeignEmitterEnabledInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          true
+        )
+      );
+    });
+
+    it("Finally Receive Message", async function() {
+      const signature = await sendAndConfirmIx(createAndReceiveIx(signedMsg));
+
+      const parsed = parseVaa(signedMsg);
+      const event = await getEvent(signature, "messageReceived");
+      expect(event.emitterChain).equals(parsed.emitterChain);
+      expect(Buffer.from(event.emitterAddress)).deep.equals(parsed.emitterAddress);
+      expect(BigInt(event.sequence.toString())).equals(parsed.sequence);
+      expect(Buffer.from(event.vaaHash)).deep.equals(parsed.hash);
+
+      const received = await helloWorld.getReceivedData(
+        connection,
+        HELLO_WORLD_PID,
+        parsed.emitterChain as ChainId, // don't do this at home, kids
+        parsed.emitterAddress,
+        parsed.sequence
+      );
+      expect(received.batchId).equals(batchId);
+      expect(received.message).deep.equals(message);
+    });
+
+    it("Cannot Call Instruction Again With Same Wormhole Message", async function() {
+      await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "already in use");
+    });
+
+    it("Close Received Account", async function() {
+      const parsed = parseVaa(signedMsg);
+      const createCloseReceivedIx = (signer: PublicKey) =>
+        helloWorld.createCloseReceivedInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          signer,
+          parsed.emitterChain as ChainId,
+          parsed.emitterAddress,
+          parsed.sequence
+        );
+
+      const nonPayer = Keypair.generate();
+      await requestAirdrop(nonPayer.publicKey);
+      await expectIxToFailWithError(
+        await createCloseReceivedIx(nonPayer.publicKey),
+        "PayerOnly",
+        nonPayer
+      );
+
+      const receivedKey = helloWorld.deriveReceivedKey(
+        HELLO_WORLD_PID,
+        parsed.emitterChain as ChainId,
+        parsed.emitterAddress,
+        parsed.sequence
+      );
+      const lamportsBefore = (await connection.getAccountInfo(receivedKey))!.lamports;
+      await expectIxToSucceed(createCloseReceivedIx(payer.publicKey));
+
+      const tombstone = (await connection.getAccountInfo(receivedKey))!;
+      expect(tombstone.lamports).lessThan(lamportsBefore);
+      expect(tombstone.data.length).equals(80);
+
+      // The tombstone still prevents replay.
+      await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "already in use");
+    });
+
+    describe("Ordered Delivery", function() {
+      const orderedEmitterChain = CHAINS.polygon;
+      const orderedEmitterAddress = Buffer.alloc(32, "0dde4ed0", "hex");
+      const orderedEmitter = new mock.MockEmitter(
+        orderedEmitterAddress.toString("hex"),
+        orderedEmitterChain
+      );
+
+      const signedMsgs = range(4).map(() => publishAndSign(createPayload(), orderedEmitter));
+      const firstSequence = parseVaa(signedMsgs[0]).sequence;
+
+      it("Register Ordered Emitter", async function() {
+        await expectIxToSucceed(
+          helloWorld.createRegisterForeignEmitterInstruction(
+            connection,
+            HELLO_WORLD_PID,
+            payer.publicKey,
+            orderedEmitterChain,
+            orderedEmitterAddress
+          )
+        );
+        await expectIxToSucceed(
+          helloWorld.createSetForeignEmitterOrderedInstruction(
+            connection,
+            HELLO_WORLD_PID,
+            payer.publicKey,
+            orderedEmitterChain,
+            orderedEmitterAddress,
+            true, // ordered
+            firstSequence
+          )
+        );
+
+        for (const signedMsg of signedMsgs) {
+          await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        }
+      });
+
+      it("Cannot Receive Message Out Of Order", async function() {
+        await expectIxToFailWithError(
+          await createAndReceiveIx(signedMsgs[1]),
+          "OutOfOrderMessage"
+        );
+      });
+
+      it("Receive Messages In Order", async function() {
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[0]));
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[1]));
+
+        const {nextExpectedSequence} = await helloWorld.getForeignEmitterData(
+          connection,
+          HELLO_WORLD_PID,
+          orderedEmitterChain,
+          orderedEmitterAddress
+        );
+        expect(nextExpectedSequence).equals(firstSequence + 2n);
+      });
+
+      it("Skip Lost Message", async function() {
+        const createSkipIx = (sequence: bigint) =>
+          helloWorld.createSkipToSequenceInstruction(
+            connection,
+            HELLO_WORLD_PID,
+            payer.publicKey,
+            orderedEmitterChain,
+            orderedEmitterAddress,
+            sequence
+          );
+
+        await expectIxToFailWithError(await createSkipIx(firstSequence), "InvalidSequenceSkip");
+        await expectIxToSucceed(createSkipIx(firstSequence + 3n));
+
+        await expectIxToFailWithError(
+          await createAndReceiveIx(signedMsgs[2]),
+          "OutOfOrderMessage"
+        );
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[3]));
+      });
+    });
+
+    describe("Acknowledgements", function() {
+      const createAckPayload = (emitterChain: number, sequence: bigint, vaaHash: Buffer) => {
+        const buf = Buffer.alloc(43);
+        buf.writeUint8(2, 0); // payload ID
+        buf.writeUint16BE(emitterChain, 1);
+        buf.writeBigUInt64BE(sequence, 3);
+        vaaHash.copy(buf, 11);
+        return buf;
+      };
+
+      const createSetAcksIx = (sendAcks: boolean) =>
+        helloWorld.createSetForeignEmitterAcksInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          sendAcks
+        );
+
+      it("Send Ack For Received Message", async function() {
+        await expectIxToSucceed(createSetAcksIx(true));
+        expect(
+          (await helloWorld.getForeignEmitterData(
+            connection,
+            HELLO_WORLD_PID,
+            realForeignEmitterChain,
+            realForeignEmitterAddress
+          )).sendAcks
+        ).is.true;
+
+        const signedMsg = publishAndSign(createPayload(), realEmitter);
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        await expectIxToSucceed(createAndReceiveIx(signedMsg));
+
+        const parsed = parseVaa(signedMsg);
+        const {payload} = (
+          await wormhole.getPostedMessage(
+            connection,
+            helloWorld.deriveAckMessageKey(
+              HELLO_WORLD_PID,
+              parsed.emitterChain as ChainId,
+              parsed.emitterAddress,
+              parsed.sequence
+            )
+          )
+        ).message;
+        expect(payload).deep.equals(
+          createAckPayload(parsed.emitterChain, parsed.sequence, parsed.hash)
+        );
+
+        await expectIxToSucceed(createSetAcksIx(false));
+      });
+
+      it("Cannot Receive Ack For Message Not Sent From Solana", async function() {
+        const signedMsg = publishAndSign(
+          createAckPayload(CHAINS.ethereum, 0n, Buffer.alloc(32, "ff", "hex")),
+          realEmitter
+        );
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "InvalidAck");
+      });
+
+      it("Receive Ack", async function() {
+        const ackedSequence = 1n;
+        const ackedVaaHash = Buffer.alloc(32, "ab", "hex");
+        const signedMsg = publishAndSign(
+          createAckPayload(CHAINS.solana, ackedSequence, ackedVaaHash),
+          realEmitter
+        );
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        await expectIxToSucceed(createAndReceiveIx(signedMsg));
+
+        const acknowledged = await helloWorld.getAcknowledgedData(
+          connection,
+          HELLO_WORLD_PID,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          ackedSequence
+        );
+        expect(acknowledged.sequence).equals(ackedSequence);
+        expect(acknowledged.vaaHash).deep.equals(ackedVaaHash);
+        expect(acknowledged.wormholeMessageHash).deep.equals(parseVaa(signedMsg).hash);
+      });
+    });
+
+    describe("Chunked Messages", function() {
+      const largeMessage = Buffer.alloc(1200, "Somebody set up us the bomb");
+      const chunkSize = helloWorld.CHUNK_MAX_LENGTH;
+      const total = Math.ceil(largeMessage.length / chunkSize);
+
+      const createChunkPayload = (
+        messageId: bigint,
+        index: number,
+        total: number,
+        hash: Buffer,
+        data: Buffer
+      ) => {
+        const buf = Buffer.alloc(47 + data.length);
+        buf.writeUint8(3, 0); // payload ID
+        buf.writeBigUInt64BE(messageId, 1);
+        buf.writeUint16BE(index, 9);
+        buf.writeUint16BE(total, 11);
+        hash.copy(buf, 13);
+        buf.writeUint16BE(data.length, 45);
+        data.copy(buf, 47);
+        return buf;
+      };
+
+      const publishChunks = (messageId: bigint, hash: Buffer) =>
+        range(total).map((index) =>
+          publishAndSign(
+            createChunkPayload(
+              messageId,
+              index,
+              total,
+              hash,
+              largeMessage.subarray(index * chunkSize, (index + 1) * chunkSize)
+            ),
+            realEmitter
+          )
+        );
+
+      const getReassembly = (messageId: bigint) =>
+        helloWorld.getReassemblyData(
+          connection,
+          HELLO_WORLD_PID,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          messageId
+        );
+
+      const createCloseReassemblyIx = (closer: PublicKey, messageId: bigint) =>
+        helloWorld.createCloseReassemblyInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          closer,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          messageId
+        );
+
+      const messageId = 1n;
+
+      it("Reassemble Chunks Received Out Of Order", async function() {
+        const signedMsgs = publishChunks(messageId, keccak256(largeMessage));
+        for (const signedMsg of signedMsgs) {
+          await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        }
+
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[2]));
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[0]));
+        expect((await getReassembly(messageId)).complete).is.false;
+
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[1]));
+        const reassembly = await getReassembly(messageId);
+        expect(reassembly.complete).is.true;
+        expect(reassembly.totalChunks).equals(total);
+        expect(reassembly.message).deep.equals(largeMessage);
+      });
+
+      it("Fail Chunked Message With Wrong Hash", async function() {
+        const badMessageId = 2n;
+        const signedMsgs = publishChunks(badMessageId, Buffer.alloc(32, "ff", "hex"));
+        for (const signedMsg of signedMsgs) {
+          await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        }
+
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[0]));
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[1]));
+
+        // The last chunk is received, but the reassembly is marked as failed
+        // instead of complete.
+        await expectIxToSucceed(createAndReceiveIx(signedMsgs[2]));
+        const reassembly = await getReassembly(badMessageId);
+        expect(reassembly.complete).is.false;
+        expect(reassembly.failed).is.true;
+
+        // So its payer can close it without waiting for it to be abandoned.
+        await expectIxToSucceed(createCloseReassemblyIx(payer.publicKey, badMessageId));
+        expect((await getReassembly(badMessageId)).closed).is.true;
+      });
+
+      it("Close Reassembly", async function() {
+        const nonPayer = Keypair.generate();
+        await requestAirdrop(nonPayer.publicKey);
+        await expectIxToFailWithError(
+          await createCloseReassemblyIx(nonPayer.publicKey, messageId),
+          "ReassemblyInProgress",
+          nonPayer
+        );
+
+        await expectIxToSucceed(createCloseReassemblyIx(payer.publicKey, messageId));
+        const reassembly = await getReassembly(messageId);
+        expect(reassembly.closed).is.true;
+        expect(reassembly.message).has.length(0);
+
+        await expectIxToFailWithError(
+          await createCloseReassemblyIx(payer.publicKey, messageId),
+          "ReassemblyClosed"
+        );
+      });
+
+      it("Cannot Receive Chunk After Reassembly Is Closed", async function() {
+        // The closed reassembly is a tombstone, so it cannot be recreated by
+        // another chunk with the same message ID.
+        const [signedMsg] = publishChunks(messageId, keccak256(largeMessage));
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        await expectIxToFailWithError(
+          await createAndReceiveIx(signedMsg),
+          "ConstraintSpace"
+        );
+      });
+    });
+
+    describe("Message Age", function() {
+      const createSetMessageAgeIx = (maxMessageAge: number, maxFutureDrift: number | null) =>
+        helloWorld.createSetForeignEmitterMessageAgeInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          realForeignEmitterAddress,
+          maxMessageAge,
+          maxFutureDrift
+        );
+
+      const publishAndSignAt = (timestamp: number) =>
+        guardianSign(realEmitter.publishMessage(batchId, createPayload(), 1, timestamp));
+
+      it("Cannot Receive Stale Or Future Message", async function() {
+        await expectIxToSucceed(createSetMessageAgeIx(3600, 60));
+        const {maxMessageAge, maxFutureDrift} = await helloWorld.getForeignEmitterData(
+          connection,
+          HELLO_WORLD_PID,
+          realForeignEmitterChain,
+          realForeignEmitterAddress
+        );
+        expect(maxMessageAge).equals(3600);
+        expect(maxFutureDrift).equals(60);
+
+        const now = Math.floor(Date.now() / 1000);
+        for (const timestamp of [now - 7200, now + 3600]) {
+          const signedMsg = publishAndSignAt(timestamp);
+          await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+          await expectIxToFailWithError(await createAndReceiveIx(signedMsg), "UntimelyMessage");
+        }
+
+        const signedMsg = publishAndSignAt(now);
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+        await expectIxToSucceed(createAndReceiveIx(signedMsg));
+      });
+
+      it("Receive Stale Message Without Limit", async function() {
+        const signedMsg = publishAndSignAt(Math.floor(Date.now() / 1000) - 7200);
+        await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+
+        await expectIxToSucceed(createSetMessageAgeIx(0, null));
+        await expectIxToSucceed(createAndReceiveIx(signedMsg));
+      });
+    });
+
+    const oldEmitter = new mock.MockEmitter(
+      oldForeignEmitterAddress.toString("hex"),
+      realForeignEmitterChain
+    );
+
+    it("Receive Message From Old Emitter On Same Chain", async function() {
+      const oldSignedMsg = publishAndSign(createPayload(), oldEmitter);
+      await expect(postSignedMsgAsVaaOnSolana(oldSignedMsg)).to.be.fulfilled;
+      await expectIxToSucceed(createAndReceiveIx(oldSignedMsg));
+    });
+
+    it("Cannot Receive Message From Deregistered Emitter", async function() {
+      await expectIxToSucceed(
+        helloWorld.createDeregisterForeignEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          realForeignEmitterChain,
+          oldForeignEmitterAddress
+        )
+      );
+
+      const oldSignedMsg = publishAndSign(createPayload(), oldEmitter);
+      await expect(postSignedMsgAsVaaOnSolana(oldSignedMsg)).to.be.fulfilled;
+      await expectIxToFailWithError(
+        await createAndReceiveIx(oldSignedMsg),
+        "AccountNotInitialized"
+      );
+    });
+  });
+
+  describe("Governance", function() {
+    const governanceChain = CHAINS.ethereum;
+    const governanceAddress = Buffer.alloc(32, "da0da0da", "hex");
+    const governanceEmitter = new mock.MockEmitter(
+      governanceAddress.toString("hex"),
+      governanceChain
+    );
+
+    const publishAndSign = (
+      action: helloWorld.GovernanceAction,
+      emitter: mock.MockEmitter = governanceEmitter,
+      targetChain: ChainId = CHAINS.solana
+    ) => {
+      const batchId = 0;
+      const finality = 1;
+      return guardianSign(
+        emitter.publishMessage(
+          batchId,
+          helloWorld.encodeGovernancePayload(targetChain, action),
+          finality
+        )
+      );
+    };
+
+    const createExecuteIx = async (signedMsg: Buffer) => {
+      await expect(postSignedMsgAsVaaOnSolana(signedMsg)).to.be.fulfilled;
+      return helloWorld.createExecuteGovernanceInstruction(
+        connection,
+        HELLO_WORLD_PID,
+        payer.publicKey,
+        CORE_BRIDGE_PID,
+        signedMsg
+      );
+    };
+
+    const pause = (paused: boolean) => ({pause: {outbound: paused, inbound: paused}});
+
+    it("Cannot Execute Governance Before Governance Emitter Is Set", async function() {
+      await expectIxToFailWithError(
+        await createExecuteIx(publishAndSign(pause(true))),
+        "InvalidGovernanceEmitter"
+      );
+    });
+
+    it("Cannot Set Governance Emitter With Zero Address", async function() {
+      await expectIxToFailWithError(
+        await helloWorld.createSetGovernanceEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          governanceChain,
+          Buffer.alloc(32)
+        ),
+        "InvalidGovernanceEmitter"
+      );
+    });
+
+    it("Set Governance Emitter", async function() {
+      await expectIxToSucceed(
+        helloWorld.createSetGovernanceEmitterInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          governanceChain,
+          governanceAddress
+        )
+      );
+      const config = await helloWorld.getConfigData(connection, HELLO_WORLD_PID);
+      expect(config.governanceChain).equals(governanceChain);
+      expect(config.governanceEmitter).deep.equals(governanceAddress);
+    });
+
+    it("Cannot Execute Governance From Another Emitter", async function() {
+      const otherEmitter = new mock.MockEmitter(
+        realForeignEmitterAddress.toString("hex"),
+        realForeignEmitterChain
+      );
+      await expectIxToFailWithError(
+        await createExecuteIx(publishAndSign(pause(true), otherEmitter)),
+        "InvalidGovernanceEmitter"
+      );
+    });
+
+    it("Cannot Execute Governance For Another Chain", async function() {
+      await expectIxToFailWithError(
+        await createExecuteIx(publishAndSign(pause(true), governanceEmitter, CHAINS.ethereum)),
+        "InvalidTargetChain"
+      );
+    });
+
+    it("Execute Pause", async function() {
+      const pauseMsg = publishAndSign(pause(true));
+      await expectIxToSucceed(createExecuteIx(pauseMsg));
+      let config = await helloWorld.getConfigData(connection, HELLO_WORLD_PID);
+      expect(config.pauseOutbound && config.pauseInbound).is.true;
+
+      await expectIxToSucceed(createExecuteIx(publishAndSign(pause(false))));
+      config = await helloWorld.getConfigData(connection, HELLO_WORLD_PID);
+      expect(config.pauseOutbound || config.pauseInbound).is.false;
+
+      // Governance VAAs cannot be replayed.
+      await expectIxToFailWithError(await createExecuteIx(pauseMsg), "already in use");
+    });
+
+    it("Execute RegisterEmitter", async function() {
+      const chain = CHAINS.bsc;
+      const address = Buffer.alloc(32, "beefdead", "hex");
+      const signature = await sendAndConfirmIx(
+        createExecuteIx(publishAndSign({registerEmitter: {chain, address}}))
+      );
+
+      const foreignEmitter =
+        await helloWorld.getForeignEmitterData(connection, HELLO_WORLD_PID, chain, address);
+      expect(foreignEmitter.chain).equals(chain);
+      expect(foreignEmitter.address).deep.equals(address);
+      expect(foreignEmitter.enabled).is.true;
+
+      const event = await getEvent(signature, "emitterRegistered");
+      expect(event.chain).equals(chain);
+      expect(Buffer.from(event.address)).deep.equals(address);
+    });
+
+    it("Cannot Execute RegisterEmitter With Solana Chain ID", async function() {
+      await expectIxToFailWithError(
+        await createExecuteIx(
+          publishAndSign({
+            registerEmitter: {chain: CHAINS.solana, address: realForeignEmitterAddress}
+          })
+        ),
+        "InvalidForeignEmitter"
+      );
+    });
+
+    it("Execute SetFee", async function() {
+      const appFee = 1_000n;
+      const feeRecipient = Keypair.generate().publicKey;
+      await expectIxToSucceed(
+        createExecuteIx(publishAndSign({setFee: {appFee, feeRecipient}}))
+      );
+      const config = await helloWorld.getConfigData(connection, HELLO_WORLD_PID);
+      expect(config.appFee).equals(appFee);
+      expect(config.feeRecipient.equals(feeRecipient)).is.true;
+
+      // Reset the fee settings.
+      await expectIxToSucceed(
+        createExecuteIx(publishAndSign({setFee: {appFee: 0n, feeRecipient: payer.publicKey}}))
+      );
+    });
+
+    it("Execute TransferOwnership", async function() {
+      const newOwner = Keypair.generate().publicKey;
+      await expectIxToSucceed(
+        createExecuteIx(publishAndSign({transferOwnership: {newOwner}}))
+      );
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).owner.equals(newOwner)
+      ).is.true;
+
+      // The previous owner cannot administer the program anymore.
+      await expectIxToFailWithError(
+        await helloWorld.createSetPauseOutboundInstruction(
+          connection,
+          HELLO_WORLD_PID,
+          payer.publicKey,
+          true
+        ),
+        "OwnerOnly"
+      );
+
+      // Transfer ownership back.
+      await expectIxToSucceed(
+        createExecuteIx(publishAndSign({transferOwnership: {newOwner: payer.publicKey}}))
+      );
+      expect(
+        (await helloWorld.getConfigData(connection, HELLO_WORLD_PID)).owner.equals(payer.publicKey)
+      ).is.true;
     });
   });
 });
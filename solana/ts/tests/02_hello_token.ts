@@ -3,9 +3,15 @@ import chaiAsPromised from 'chai-as-promised';
 chaiUse(chaiAsPromised)
 import {
   Connection,
+  Keypair,
   PublicKey
 } from "@solana/web3.js";
-import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import {
   CHAINS,
   ChainId,
@@ -288,6 +294,37 @@ describe(" 2: Hello Token", function() {
   const getTokenBalance = async (tokenAccount: PublicKey) =>
     (await getAccount(connection, tokenAccount)).amount;
 
+  describe("Token-2022 Mint", function() {
+    it("Cannot Send Token-2022 Mint", async function() {
+      const mint = await createMint(
+        connection,
+        payer,
+        payer.publicKey,
+        null,
+        8,
+        Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // The payer cannot hold this mint in an SPL Token account, so send from
+      // one of its existing token accounts instead. The mint is rejected before
+      // the token account is checked against it.
+      const ix = await helloToken.createSendNativeTokensWithPayloadInstruction(
+        connection,
+        HELLO_TOKEN_PID,
+        payer.publicKey,
+        TOKEN_BRIDGE_PID,
+        CORE_BRIDGE_PID,
+        mint,
+        {batchId, amount: sendAmount, recipientAddress, recipientChain: foreignChain},
+        getAssociatedTokenAddressSync(MINTS_WITH_DECIMALS.get(8)!.publicKey, payer.publicKey)
+      );
+
+      await expectIxToFailWithError(ix, "UnsupportedMint");
+    });
+  });
+
   ([
     [
       false,